@@ -0,0 +1,204 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, Result};
+use console::Term;
+
+use crate::{clue::Clue, config::Config};
+
+/// What the player has worked out about a single symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Note {
+    #[default]
+    Unknown,
+    /// The symbol is not in the secret.
+    RuledOut,
+    /// The symbol is in the secret but its position is unknown.
+    Confirmed,
+    /// The symbol is in the secret at this zero based position.
+    Position(usize),
+}
+
+impl Display for Note {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Note::Unknown => write!(f, "?"),
+            Note::RuledOut => write!(f, "x"),
+            Note::Confirmed => write!(f, "+"),
+            Note::Position(position) => write!(f, "{}", position + 1),
+        }
+    }
+}
+
+/// A note for every symbol of the alphabet.
+#[derive(Debug, Clone)]
+pub struct Notepad {
+    notes: Vec<(char, Note)>,
+}
+
+impl Notepad {
+    /// Creates an empty [`Notepad`] for the symbols in `config`.
+    pub fn new(config: &Config) -> Self {
+        Self {
+            notes: config
+                .alphabet
+                .symbols()
+                .chars()
+                .map(|symbol| (symbol, Note::Unknown))
+                .collect(),
+        }
+    }
+
+    /// Changes the note for `symbol` if it is in the alphabet.
+    pub fn set(&mut self, symbol: char, note: Note) {
+        if let Some((_, current)) = self.notes.iter_mut().find(|(s, _)| *s == symbol) {
+            *current = note;
+        }
+    }
+
+    /// The note for `symbol`.
+    pub fn get(&self, symbol: char) -> Note {
+        self.notes
+            .iter()
+            .find(|(s, _)| *s == symbol)
+            .map_or(Note::Unknown, |(_, note)| *note)
+    }
+}
+
+impl Display for Notepad {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Notepad:")?;
+        for (symbol, _) in &self.notes {
+            write!(f, " {symbol}")?;
+        }
+
+        writeln!(f)?;
+        write!(f, "        ")?;
+        for (_, note) in &self.notes {
+            write!(f, " {note}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The guesses of the current game with their clues and the player's notes,
+/// redrawn after every turn.
+pub struct Board {
+    config: Config,
+    history: Vec<(String, Clue)>,
+    pub notepad: Notepad,
+}
+
+impl Board {
+    /// Creates an empty [`Board`] for a game with the given settings.
+    pub fn new(config: &Config) -> Self {
+        Self {
+            config: *config,
+            history: vec![],
+            notepad: Notepad::new(config),
+        }
+    }
+
+    /// Adds a scored guess to the history and fills in the notes that
+    /// follow directly from its clues.
+    pub fn record(&mut self, guess: &str, clue: Clue) {
+        self.history.push((guess.to_owned(), clue));
+
+        if clue.is_solved(self.config.digits) {
+            for (position, symbol) in guess.chars().enumerate() {
+                self.notepad.set(symbol, Note::Position(position));
+            }
+        } else if clue.is_bagels() {
+            for symbol in guess.chars() {
+                self.notepad.set(symbol, Note::RuledOut);
+            }
+        } else if !self.config.allow_repeats && clue.total() == self.config.digits {
+            // Every symbol of the guess is in the secret, so nothing else is.
+            for symbol in self.config.alphabet.symbols().chars() {
+                if !guess.contains(symbol) {
+                    self.notepad.set(symbol, Note::RuledOut);
+                } else if self.notepad.get(symbol) == Note::Unknown {
+                    self.notepad.set(symbol, Note::Confirmed);
+                }
+            }
+        }
+    }
+
+    /// Clears the terminal and draws the board.
+    pub fn draw(&self, term: &Term) -> Result<()> {
+        term.clear_screen()?;
+        term.write_line(&format!("Bagels - {}", self.config))?;
+        let language = self.config.language;
+        term.write_line(&format!(
+            "{}: right place. {}: wrong place. {}: nothing is right.",
+            language.fermi(),
+            language.pico(),
+            language.bagels()
+        ))?;
+        term.write_line("")?;
+
+        let width = self.config.digits.max("Guess".len());
+        term.write_line(&format!(" #  {:width$}  Clues", "Guess"))?;
+
+        for (i, (guess, clue)) in self.history.iter().enumerate() {
+            let clue = language.render(*clue, self.config.compact_clues);
+            term.write_line(&format!("{:>2}  {guess:width$}  {clue}", i + 1))?;
+        }
+
+        term.write_line("")?;
+        term.write_line(&self.notepad.to_string())?;
+        term.write_line("Commands: hint, mark <symbols> out | in | at <position> | clear")?;
+        term.write_line("")?;
+
+        Ok(())
+    }
+}
+
+/// Applies a `mark` command such as `mark 47 out` or `mark 5 at 2` to the
+/// notepad. Returns `None` if `input` is not a `mark` command.
+pub fn apply_mark(input: &str, notepad: &mut Notepad, config: &Config) -> Option<Result<()>> {
+    let mut words = input.split_whitespace();
+
+    if !words.next()?.eq_ignore_ascii_case("mark") {
+        return None;
+    }
+
+    Some(parse_mark(words.collect(), config).map(|(symbols, note)| {
+        for symbol in symbols {
+            notepad.set(symbol, note);
+        }
+    }))
+}
+
+/// Parses the arguments of a `mark` command into symbols and a note.
+fn parse_mark(words: Vec<&str>, config: &Config) -> Result<(Vec<char>, Note)> {
+    let usage = "Use: mark <symbols> out | in | at <position> | clear";
+
+    let (symbols, rest) = words.split_first().ok_or_else(|| anyhow!(usage))?;
+    let symbols: Vec<char> = symbols.to_uppercase().chars().collect();
+
+    if let Some(symbol) = symbols.iter().find(|&&s| !config.alphabet.contains(s)) {
+        return Err(anyhow!(
+            "'{symbol}' is not allowed. Use only {}.",
+            config.alphabet.description()
+        ));
+    }
+
+    let note = match rest {
+        ["out" | "x"] => Note::RuledOut,
+        ["in" | "+"] => Note::Confirmed,
+        ["clear" | "?"] => Note::Unknown,
+        ["at", position] | [position] => {
+            let position = position
+                .parse::<usize>()
+                .ok()
+                .filter(|position| (1..=config.digits).contains(position))
+                .ok_or_else(|| anyhow!("The position must be between 1 and {}.", config.digits))?;
+
+            Note::Position(position - 1)
+        }
+        _ => return Err(anyhow!(usage)),
+    };
+
+    Ok((symbols, note))
+}
@@ -0,0 +1,349 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::{anyhow, Result};
+
+/// The result of scoring a guess against the secret.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Clue {
+    /// Symbols that are correct and in the right position.
+    pub fermi: u8,
+    /// Symbols that are correct but in the wrong position.
+    pub pico: u8,
+}
+
+impl Clue {
+    /// The clue for a guess that matches a secret of `digits` symbols.
+    pub fn solved(digits: usize) -> Self {
+        Self {
+            fermi: digits as u8,
+            pico: 0,
+        }
+    }
+
+    /// Checks if no symbol of the guess is in the secret.
+    pub fn is_bagels(&self) -> bool {
+        self.fermi == 0 && self.pico == 0
+    }
+
+    /// Checks if every one of the `digits` symbols is in the right position.
+    pub fn is_solved(&self, digits: usize) -> bool {
+        usize::from(self.fermi) == digits
+    }
+
+    /// How many symbols of the guess are in the secret.
+    pub fn total(&self) -> usize {
+        usize::from(self.fermi) + usize::from(self.pico)
+    }
+}
+
+/// Writes the clue as words, e.g. `Fermi Pico Pico` or `Bagels`. The
+/// alternate form (`{:#}`) is compact, e.g. `1F 2P` or `B`.
+impl Display for Clue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Language::English.render(*self, f.alternate()))
+    }
+}
+
+impl FromStr for Clue {
+    type Err = anyhow::Error;
+
+    /// Parses English clues, see [`Language::parse`].
+    fn from_str(s: &str) -> Result<Self> {
+        Language::English.parse(s)
+    }
+}
+
+/// The language clues are shown in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+    German,
+}
+
+impl Language {
+    /// The word for a correct symbol in the right position.
+    pub fn fermi(&self) -> &'static str {
+        match self {
+            Language::English => "Fermi",
+            Language::Spanish => "Fija",
+            Language::German => "Treffer",
+        }
+    }
+
+    /// The word for a correct symbol in the wrong position.
+    pub fn pico(&self) -> &'static str {
+        match self {
+            Language::English => "Pico",
+            Language::Spanish => "Pica",
+            Language::German => "Fast",
+        }
+    }
+
+    /// The word for a guess without any correct symbols.
+    pub fn bagels(&self) -> &'static str {
+        match self {
+            Language::English => "Bagels",
+            Language::Spanish => "Rosquillas",
+            Language::German => "Brezeln",
+        }
+    }
+
+    /// The message for a guess that matches the secret.
+    pub fn solved(&self) -> &'static str {
+        match self {
+            Language::English => "You got it!",
+            Language::Spanish => "¡Lo has adivinado!",
+            Language::German => "Richtig geraten!",
+        }
+    }
+
+    /// Parses clues in this language such as `Fermi Pico`, `FermiPico`,
+    /// `f p`, `2F 1P` or `Bagels`. Every word can be shortened to its first
+    /// letter, which is different for each word.
+    pub fn parse(&self, s: &str) -> Result<Clue> {
+        let invalid = || {
+            anyhow!(
+                "Please answer with {}, {} or {}.",
+                self.fermi(),
+                self.pico(),
+                self.bagels()
+            )
+        };
+        // A count this large is a typo, so it is rejected rather than wrapped.
+        let add = |count: &mut u8, number: u8| -> Result<()> {
+            *count = count.checked_add(number).ok_or_else(invalid)?;
+            Ok(())
+        };
+        let words = [self.fermi(), self.pico(), self.bagels()].map(str::to_lowercase);
+        let initials = words.clone().map(|word| word[..1].to_owned());
+        let mut clue = Clue::default();
+        let mut bagels = false;
+
+        for word in s.split_whitespace() {
+            let word = word.to_lowercase();
+            let number_len = word.chars().take_while(char::is_ascii_digit).count();
+
+            if number_len > 0 {
+                let (number, kind) = word.split_at(number_len);
+                let number: u8 = number.parse().map_err(|_| invalid())?;
+
+                if kind == words[0] || kind == initials[0] {
+                    add(&mut clue.fermi, number)?;
+                } else if kind == words[1] || kind == initials[1] {
+                    add(&mut clue.pico, number)?;
+                } else {
+                    return Err(invalid());
+                }
+
+                continue;
+            }
+
+            let mut rest = word.as_str();
+
+            while !rest.is_empty() {
+                // Whole words are tried before first letters.
+                let (index, after) = words
+                    .iter()
+                    .chain(&initials)
+                    .enumerate()
+                    .find_map(|(i, word)| {
+                        rest.strip_prefix(word.as_str()).map(|after| (i % 3, after))
+                    })
+                    .ok_or_else(invalid)?;
+
+                match index {
+                    0 => add(&mut clue.fermi, 1)?,
+                    1 => add(&mut clue.pico, 1)?,
+                    _ => bagels = true,
+                }
+                rest = after;
+            }
+        }
+
+        // Bagels can't be combined with other clues, and an empty answer is
+        // not a clue at all.
+        if bagels != clue.is_bagels() {
+            return Err(invalid());
+        }
+
+        Ok(clue)
+    }
+
+    /// Renders `clue` as words, or as counts such as `2F 1P` when `compact`
+    /// is set.
+    pub fn render(&self, clue: Clue, compact: bool) -> String {
+        if clue.is_bagels() {
+            return if compact {
+                self.bagels()[..1].to_owned()
+            } else {
+                self.bagels().to_owned()
+            };
+        }
+
+        let counts = [(clue.fermi, self.fermi()), (clue.pico, self.pico())];
+        let words: Vec<String> = if compact {
+            counts
+                .iter()
+                .filter(|(count, _)| *count > 0)
+                .map(|(count, word)| format!("{count}{}", &word[..1]))
+                .collect()
+        } else {
+            counts
+                .iter()
+                .flat_map(|(count, word)| std::iter::repeat_n(word.to_string(), *count as usize))
+                .collect()
+        };
+
+        words.join(" ")
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Language::English => "english",
+                Language::Spanish => "spanish",
+                Language::German => "german",
+            }
+        )
+    }
+}
+
+impl FromStr for Language {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "english" | "en" => Ok(Language::English),
+            "spanish" | "es" => Ok(Language::Spanish),
+            "german" | "de" => Ok(Language::German),
+            _ => Err(anyhow!(
+                "Unknown language `{s}`. Use english, spanish or german."
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::Config, get_clues, solver::Solver};
+
+    /// Every possible secret for a configuration.
+    fn all_secrets(digits: usize, allow_repeats: bool) -> Vec<String> {
+        let config = Config {
+            digits,
+            allow_repeats,
+            ..Config::default()
+        };
+
+        Solver::new(&config)
+            .expect("The space is small enough")
+            .candidates()
+            .to_vec()
+    }
+
+    /// How many symbols two strings have in common, counting repeats.
+    fn common_symbols(a: &str, b: &str) -> usize {
+        let mut rest: Vec<char> = b.chars().collect();
+
+        a.chars()
+            .filter(|symbol| {
+                rest.iter()
+                    .position(|other| other == symbol)
+                    .map(|index| rest.swap_remove(index))
+                    .is_some()
+            })
+            .count()
+    }
+
+    #[test]
+    fn clues_are_consistent_without_repeats() {
+        let secrets = all_secrets(3, false);
+
+        for secret in &secrets {
+            for guess in &secrets {
+                let clue = get_clues(guess, secret);
+                let in_place = guess.chars().zip(secret.chars()).filter(|(a, b)| a == b);
+
+                assert_eq!(usize::from(clue.fermi), in_place.count());
+                assert_eq!(clue.total(), common_symbols(guess, secret));
+                assert_eq!(clue, get_clues(secret, guess));
+                assert_eq!(clue.is_solved(3), guess == secret);
+                // With distinct symbols only two in place means the third
+                // can't be misplaced.
+                assert_ne!((clue.fermi, clue.pico), (2, 1));
+            }
+        }
+    }
+
+    #[test]
+    fn clues_are_consistent_with_repeats() {
+        let secrets = all_secrets(3, true);
+
+        for secret in &secrets {
+            for guess in &secrets {
+                let clue = get_clues(guess, secret);
+
+                assert!(clue.total() <= 3);
+                assert_eq!(clue.total(), common_symbols(guess, secret));
+                assert_eq!(clue, get_clues(secret, guess));
+                assert_eq!(clue.is_solved(3), guess == secret);
+            }
+        }
+    }
+
+    #[test]
+    fn clues_round_trip_through_text() {
+        for fermi in 0..=4 {
+            for pico in 0..=(4 - fermi) {
+                let clue = Clue { fermi, pico };
+
+                assert_eq!(clue.to_string().parse::<Clue>().unwrap(), clue);
+                assert_eq!(format!("{clue:#}").parse::<Clue>().unwrap(), clue);
+            }
+        }
+    }
+
+    #[test]
+    fn clues_render_compactly() {
+        assert_eq!(format!("{:#}", Clue { fermi: 2, pico: 1 }), "2F 1P");
+        assert_eq!(format!("{:#}", Clue { fermi: 0, pico: 3 }), "3P");
+        assert_eq!(format!("{}", Clue { fermi: 1, pico: 2 }), "Fermi Pico Pico");
+        assert_eq!(format!("{}", Clue::default()), "Bagels");
+    }
+
+    #[test]
+    fn clues_round_trip_in_every_language() {
+        for language in [Language::English, Language::Spanish, Language::German] {
+            for fermi in 0..=4 {
+                for pico in 0..=(4 - fermi) {
+                    let clue = Clue { fermi, pico };
+
+                    for compact in [false, true] {
+                        let text = language.render(clue, compact);
+                        assert_eq!(language.parse(&text).unwrap(), clue, "{text}");
+                    }
+                }
+            }
+        }
+
+        assert_eq!(
+            Language::German.parse("f t").unwrap(),
+            Clue { fermi: 1, pico: 1 }
+        );
+        assert!(Language::Spanish.parse("Fermi").is_err());
+    }
+
+    #[test]
+    fn huge_clue_counts_are_rejected() {
+        assert!("200F 100F".parse::<Clue>().is_err());
+        assert!("255P p".parse::<Clue>().is_err());
+        assert!("300F".parse::<Clue>().is_err());
+    }
+}
@@ -0,0 +1,327 @@
+use std::{fmt::Display, str::FromStr, time::Duration};
+
+use anyhow::{anyhow, Result};
+
+use crate::clue::Language;
+
+/// The shortest secret number that can be played.
+pub const MIN_DIGITS: usize = 2;
+/// The longest secret number that can be played.
+pub const MAX_DIGITS: usize = 10;
+
+/// The set of symbols that secret numbers and guesses are made of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Alphabet {
+    #[default]
+    Decimal,
+    Hex,
+    Letters,
+}
+
+impl Alphabet {
+    /// Every symbol of the alphabet in order.
+    pub fn symbols(&self) -> &'static str {
+        match self {
+            Alphabet::Decimal => "0123456789",
+            Alphabet::Hex => "0123456789ABCDEF",
+            Alphabet::Letters => "ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+        }
+    }
+
+    /// Checks if `symbol` belongs to the alphabet.
+    pub fn contains(&self, symbol: char) -> bool {
+        self.symbols().contains(symbol)
+    }
+
+    /// The symbols of the alphabet written as ranges.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Alphabet::Decimal => "the digits 0-9",
+            Alphabet::Hex => "the digits 0-9 and A-F",
+            Alphabet::Letters => "the letters A-Z",
+        }
+    }
+
+    /// What a single symbol is called in the game text.
+    pub fn symbol_name(&self) -> &'static str {
+        match self {
+            Alphabet::Decimal | Alphabet::Hex => "digit",
+            Alphabet::Letters => "letter",
+        }
+    }
+
+    /// What the secret is called in the game text.
+    pub fn secret_name(&self) -> &'static str {
+        match self {
+            Alphabet::Decimal => "number",
+            Alphabet::Hex => "hexadecimal number",
+            Alphabet::Letters => "code",
+        }
+    }
+}
+
+impl Display for Alphabet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Alphabet::Decimal => "decimal",
+                Alphabet::Hex => "hex",
+                Alphabet::Letters => "letters",
+            }
+        )
+    }
+}
+
+impl FromStr for Alphabet {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "decimal" | "digits" | "d" => Ok(Alphabet::Decimal),
+            "hex" | "hexadecimal" | "h" => Ok(Alphabet::Hex),
+            "letters" | "alpha" | "l" => Ok(Alphabet::Letters),
+            _ => Err(anyhow!(
+                "Unknown alphabet `{s}`. Use decimal, hex or letters."
+            )),
+        }
+    }
+}
+
+/// A countdown that the player has to guess against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeLimit {
+    /// Every guess has to be made within this time.
+    PerGuess(Duration),
+    /// The whole round has to be solved within this time.
+    PerGame(Duration),
+}
+
+impl Display for TimeLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeLimit::PerGuess(limit) => write!(f, "guess:{}", limit.as_secs()),
+            TimeLimit::PerGame(limit) => write!(f, "game:{}", limit.as_secs()),
+        }
+    }
+}
+
+/// The settings a game of Bagels is played with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// How many symbols the secret number has.
+    pub digits: usize,
+    /// How many guesses the player gets, `None` means unlimited.
+    pub max_guesses: Option<u32>,
+    /// The symbols the secret number is made of.
+    pub alphabet: Alphabet,
+    /// Whether a symbol may appear more than once in the secret number.
+    pub allow_repeats: bool,
+    /// Whether invalid guesses still use up one of the guesses.
+    pub hard_mode: bool,
+    /// The language clues are shown in.
+    pub language: Language,
+    /// Whether clues are shown as counts such as `2F 1P`.
+    pub compact_clues: bool,
+    /// The countdown for speed games, `None` means there is no time limit.
+    pub time_limit: Option<TimeLimit>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            digits: 3,
+            max_guesses: Some(10),
+            alphabet: Alphabet::default(),
+            allow_repeats: false,
+            hard_mode: false,
+            language: Language::default(),
+            compact_clues: false,
+            time_limit: None,
+        }
+    }
+}
+
+impl Config {
+    /// Builds a [`Config`] from command line arguments such as
+    /// `--digits 4 --guesses unlimited --alphabet hex --repeats --hard --language es --compact --time guess:30`.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        let mut config = Config::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| anyhow!("Missing value for `{arg}`."))
+            };
+
+            match arg.as_str() {
+                "--digits" | "-d" => config.digits = parse_digits(&value()?)?,
+                "--guesses" | "-g" => config.max_guesses = parse_max_guesses(&value()?)?,
+                "--alphabet" | "-a" => config.alphabet = value()?.parse()?,
+                "--repeats" | "-r" => config.allow_repeats = true,
+                "--hard" => config.hard_mode = true,
+                "--language" | "-l" => config.language = value()?.parse()?,
+                "--compact" | "-c" => config.compact_clues = true,
+                "--time" | "-t" => config.time_limit = parse_time_limit(&value()?)?,
+                _ => return Err(anyhow!("Unknown argument `{arg}`.")),
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// The introduction and clue explanation for the current settings.
+    pub fn intro(&self) -> String {
+        let digits = self.digits;
+        let symbol = self.alphabet.symbol_name();
+        let secret = self.alphabet.secret_name();
+        let repeats = if self.allow_repeats {
+            format!("where {symbol}s may repeat")
+        } else {
+            format!("with no repeated {symbol}s")
+        };
+        let (example_secret, example_guess) = self.example();
+        let example_clues = self
+            .language
+            .render(crate::get_clues(&example_guess, &example_secret), false);
+        let (fermi, pico, bagels) = (
+            self.language.fermi(),
+            self.language.pico(),
+            self.language.bagels(),
+        );
+
+        format!(
+            r#"
+Bagels, a deductive logic game.
+
+I am thinking of a {digits}-{symbol} {secret} {repeats}.
+Try to guess what it is. Here are some clues:
+When I say:     That means:
+{pico:16}One {symbol} is correct but in the wrong position.
+{fermi:16}One {symbol} is correct and in the right position.
+{bagels:16}No {symbol} is correct.
+
+For example, if the secret {secret} was {example_secret} and your guess was {example_guess}, the
+clues would be {example_clues}.
+"#
+        )
+    }
+
+    /// A secret and guess pair used to explain the clues.
+    fn example(&self) -> (String, String) {
+        let symbols: Vec<char> = self.alphabet.symbols().chars().collect();
+
+        // Stepping through the alphabet by three never repeats a symbol
+        // because none of the alphabet lengths are a multiple of three.
+        let secret: Vec<char> = (0..self.digits)
+            .map(|i| symbols[(i * 3 + 2) % symbols.len()])
+            .collect();
+
+        let mut guess = secret.clone();
+        guess.swap(0, 1);
+
+        (secret.into_iter().collect(), guess.into_iter().collect())
+    }
+}
+
+impl Display for Config {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}s, ", self.digits, self.alphabet.symbol_name())?;
+
+        match self.max_guesses {
+            Some(max_guesses) => write!(f, "{max_guesses} guesses, ")?,
+            None => write!(f, "unlimited guesses, ")?,
+        }
+
+        write!(f, "{} alphabet", self.alphabet)?;
+
+        if self.allow_repeats {
+            write!(f, ", repeats allowed")?;
+        }
+
+        if self.hard_mode {
+            write!(f, ", hard mode")?;
+        }
+
+        if self.language != Language::default() {
+            write!(f, ", {} clues", self.language)?;
+        }
+
+        if self.compact_clues {
+            write!(f, ", compact clues")?;
+        }
+
+        match self.time_limit {
+            Some(TimeLimit::PerGuess(limit)) => write!(f, ", {}s per guess", limit.as_secs())?,
+            Some(TimeLimit::PerGame(limit)) => write!(f, ", {}s per game", limit.as_secs())?,
+            None => {}
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses and range checks a number length.
+pub fn parse_digits(input: &str) -> Result<usize> {
+    let digits = input
+        .trim()
+        .parse::<usize>()
+        .map_err(|_| anyhow!("`{input}` is not a number length."))?;
+
+    if !(MIN_DIGITS..=MAX_DIGITS).contains(&digits) {
+        return Err(anyhow!(
+            "The number length must be between {MIN_DIGITS} and {MAX_DIGITS}."
+        ));
+    }
+
+    Ok(digits)
+}
+
+/// Parses a yes or no answer.
+pub fn parse_yes_no(input: &str) -> Result<bool> {
+    match input.trim().to_lowercase().as_str() {
+        "yes" | "y" => Ok(true),
+        "no" | "n" => Ok(false),
+        _ => Err(anyhow!("Please answer yes or no.")),
+    }
+}
+
+/// Parses a time limit such as `guess:30` or `game:120`, where `none`
+/// removes the limit.
+pub fn parse_time_limit(input: &str) -> Result<Option<TimeLimit>> {
+    let input = input.trim().to_lowercase();
+
+    if input == "none" || input == "0" {
+        return Ok(None);
+    }
+
+    let invalid =
+        || anyhow!("`{input}` is not a time limit. Use none, guess:<seconds> or game:<seconds>.");
+    let (kind, seconds) = input.split_once(':').ok_or_else(invalid)?;
+    let seconds = seconds
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .filter(|&seconds| seconds > 0)
+        .ok_or_else(invalid)?;
+    let limit = Duration::from_secs(seconds);
+
+    match kind.trim() {
+        "guess" => Ok(Some(TimeLimit::PerGuess(limit))),
+        "game" => Ok(Some(TimeLimit::PerGame(limit))),
+        _ => Err(invalid()),
+    }
+}
+
+/// Parses a guess limit, where `0` or `unlimited` removes the limit.
+pub fn parse_max_guesses(input: &str) -> Result<Option<u32>> {
+    match input.trim().to_lowercase().as_str() {
+        "unlimited" | "none" | "0" => Ok(None),
+        value => value
+            .parse::<u32>()
+            .map(Some)
+            .map_err(|_| anyhow!("`{input}` is not a guess limit.")),
+    }
+}
@@ -0,0 +1,258 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, Result};
+use console::Term;
+
+use crate::{
+    clue::Clue,
+    config::Config,
+    game::{self, Outcome, Round},
+    input::prompt,
+};
+
+/// The day number of the first daily puzzle, 2024-01-01.
+const FIRST_DAY: u64 = 19_723;
+/// The file the last daily attempt is stored in, inside the home directory.
+const RECORD_FILE: &str = ".bagels_daily";
+
+/// Plays today's puzzle, which has the same secret and settings for
+/// everyone. Each puzzle can only be attempted once.
+pub fn play(config: &Config, term: &Term) -> Result<()> {
+    let config = &puzzle_config(config)?;
+    let day = today()?;
+    let number = day.saturating_sub(FIRST_DAY) + 1;
+    let record = record_path()?;
+
+    if let Some(previous) = load_record(&record, day) {
+        println!("You already played Bagels daily #{number} ({}).", date(day));
+
+        match previous {
+            Some(grid) => {
+                println!();
+                println!("{grid}");
+            }
+            None => println!("That attempt was not finished."),
+        }
+
+        println!("Come back tomorrow for a new puzzle!");
+        return Ok(());
+    }
+
+    print!("{}", config.intro());
+    println!("This is Bagels daily #{number} for {}.", date(day));
+    println!("Everybody gets the same secret today, and you only get one attempt.");
+    prompt("Press Enter to start...")?;
+
+    // Store the attempt before playing so quitting halfway does not give a
+    // second try.
+    save_record(&record, day, None)?;
+
+    let secret_num = daily_secret(day, config);
+    let round = game::play_round(config, term, &secret_num)?;
+    let grid = share_grid(number, config, &round);
+
+    save_record(&record, day, Some(&grid))?;
+
+    println!();
+    println!("Share your result:");
+    println!();
+    println!("{grid}");
+    Ok(())
+}
+
+/// The settings of the daily puzzle. Only the way clues are shown can be
+/// changed, since everything else would make it a different puzzle.
+fn puzzle_config(config: &Config) -> Result<Config> {
+    let puzzle = Config {
+        language: config.language,
+        compact_clues: config.compact_clues,
+        ..Config::default()
+    };
+
+    if *config != puzzle {
+        return Err(anyhow!(
+            "The daily puzzle always uses the standard settings. Only --language and \
+             --compact can be changed."
+        ));
+    }
+
+    Ok(puzzle)
+}
+
+/// The number of whole days since the Unix epoch in UTC.
+fn today() -> Result<u64> {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|_| anyhow!("The system clock is set before 1970."))?
+        .as_secs();
+
+    Ok(seconds / 86_400)
+}
+
+/// Formats a day number as a `YYYY-MM-DD` date.
+fn date(day: u64) -> String {
+    // Converts days to a civil date, counting in 400 year eras that start on
+    // the 1st of March so leap days fall at the end of each year.
+    let days = day as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day_of_month = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{year:04}-{month:02}-{day_of_month:02}")
+}
+
+/// Derives the secret for `day` from the date and settings alone, so every
+/// player gets the same one.
+fn daily_secret(day: u64, config: &Config) -> String {
+    let mut seed = day
+        ^ ((config.digits as u64) << 32)
+        ^ ((config.alphabet.symbols().len() as u64) << 40)
+        ^ (u64::from(config.allow_repeats) << 48);
+    let mut symbols: Vec<char> = config.alphabet.symbols().chars().collect();
+
+    if config.allow_repeats {
+        return (0..config.digits)
+            .map(|_| symbols[(split_mix(&mut seed) % symbols.len() as u64) as usize])
+            .collect();
+    }
+
+    // A Fisher-Yates shuffle with a generator that is defined here, so the
+    // secret never changes with the version of the `rand` crate.
+    for i in (1..symbols.len()).rev() {
+        let j = (split_mix(&mut seed) % (i as u64 + 1)) as usize;
+        symbols.swap(i, j);
+    }
+
+    symbols.into_iter().take(config.digits).collect()
+}
+
+/// The SplitMix64 pseudo random number generator.
+fn split_mix(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// A spoiler free summary of the round: one row per guess with a green
+/// square for every Fermi, a yellow one for every Pico and grey for the rest.
+fn share_grid(number: u64, config: &Config, round: &Round) -> String {
+    let score = match round.outcome {
+        Outcome::Won { guesses } => guesses.to_string(),
+        Outcome::Lost | Outcome::Quit => String::from("X"),
+    };
+    let limit = config
+        .max_guesses
+        .map_or(String::from("∞"), |max_guesses| max_guesses.to_string());
+
+    let mut grid = format!("Bagels daily #{number} {score}/{limit}");
+
+    for clue in &round.clues {
+        grid.push('\n');
+        grid.push_str(&row(*clue, config.digits));
+    }
+
+    grid
+}
+
+/// The squares for a single clue.
+fn row(clue: Clue, digits: usize) -> String {
+    let fermi = usize::from(clue.fermi);
+    let pico = usize::from(clue.pico);
+
+    "🟩".repeat(fermi) + &"🟨".repeat(pico) + &"⬜".repeat(digits.saturating_sub(fermi + pico))
+}
+
+/// Where the last daily attempt is stored.
+fn record_path() -> Result<PathBuf> {
+    std::env::var_os("HOME")
+        .map(|home| PathBuf::from(home).join(RECORD_FILE))
+        .ok_or_else(|| anyhow!("HOME is not set, so there is nowhere to store the daily result."))
+}
+
+/// Looks up an earlier attempt at today's puzzle. Returns `Some(None)` for an
+/// unfinished attempt and `Some(Some(grid))` for a finished one.
+///
+/// The record is the day on the first line, followed by the result grid once
+/// the attempt is finished.
+fn load_record(path: &Path, day: u64) -> Option<Option<String>> {
+    let contents = fs::read_to_string(path).ok()?;
+    let (key, grid) = contents.split_once('\n').unwrap_or((&contents, ""));
+    let grid = grid.trim();
+
+    (key.trim() == day.to_string()).then(|| (!grid.is_empty()).then(|| grid.to_owned()))
+}
+
+/// Stores the attempt at today's puzzle in the format read by
+/// [`load_record`], replacing the attempt from an earlier day.
+fn save_record(path: &Path, day: u64, grid: Option<&str>) -> Result<()> {
+    let contents = match grid {
+        Some(grid) => format!("{day}\n{grid}\n"),
+        None => format!("{day}\n"),
+    };
+
+    fs::write(path, contents).map_err(|error| {
+        anyhow!(
+            "Could not save the daily result to {}: {error}",
+            path.display()
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates_follow_the_calendar() {
+        assert_eq!(date(0), "1970-01-01");
+        assert_eq!(date(FIRST_DAY), "2024-01-01");
+
+        let parse = |date: &str| -> (u64, u64, u64) {
+            let parts: Vec<u64> = date.split('-').map(|part| part.parse().unwrap()).collect();
+            (parts[0], parts[1], parts[2])
+        };
+        let days_in_month = |year: u64, month: u64| match month {
+            2 if year.is_multiple_of(4)
+                && (!year.is_multiple_of(100) || year.is_multiple_of(400)) =>
+            {
+                29
+            }
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        };
+
+        // Every day up to the year 9999 must be the day after the one before.
+        let mut previous = parse(&date(0));
+        for day in 1..2_932_897 {
+            let (year, month, day_of_month) = previous;
+            let expected = if day_of_month < days_in_month(year, month) {
+                (year, month, day_of_month + 1)
+            } else if month < 12 {
+                (year, month + 1, 1)
+            } else {
+                (year + 1, 1, 1)
+            };
+
+            previous = parse(&date(day));
+            assert_eq!(previous, expected, "day {day}");
+        }
+        assert_eq!(date(2_932_896), "9999-12-31");
+    }
+}
@@ -0,0 +1,267 @@
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use console::Term;
+
+use crate::{
+    board::{self, Board},
+    clue::Clue,
+    config::{Config, TimeLimit},
+    get_clues,
+    guess::validate_guess,
+    hint,
+    input::{self, Timed},
+};
+
+/// How a single round of Bagels ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// The secret was found after this many guesses.
+    Won { guesses: u32 },
+    /// The player ran out of guesses or time.
+    Lost,
+    /// The input was closed before the round was over.
+    Quit,
+}
+
+/// A finished round of Bagels.
+#[derive(Debug, Clone)]
+pub struct Round {
+    pub outcome: Outcome,
+    /// The clue for every scored guess, in order.
+    pub clues: Vec<Clue>,
+    /// How long the player spent guessing.
+    pub elapsed: Duration,
+}
+
+impl Round {
+    /// The speed game score of a won round. Every guess after the first costs
+    /// 75 points and every second costs 5, starting from 1000.
+    pub fn score(&self) -> Option<u32> {
+        let Outcome::Won { guesses } = self.outcome else {
+            return None;
+        };
+
+        let penalty = 75 * (guesses - 1) + 5 * self.elapsed.as_secs() as u32;
+        Some(1_000u32.saturating_sub(penalty).max(1))
+    }
+}
+
+/// One player's progress through a round, played a guess at a time.
+pub struct RoundState<'a> {
+    config: &'a Config,
+    secret_num: String,
+    /// Shown above the messages, e.g. whose turn it is.
+    title: Option<String>,
+    board: Board,
+    /// Messages shown below the board the next time it is drawn.
+    status: Vec<String>,
+    num_guesses: u32,
+    previous_guesses: Vec<String>,
+    clue_history: Vec<(String, Clue)>,
+    elapsed: Duration,
+}
+
+impl<'a> RoundState<'a> {
+    /// Starts a round where the player has to guess `secret_num`.
+    pub fn new(config: &'a Config, secret_num: &str) -> Self {
+        let mut status = vec![format!(
+            "I have thought up a {}. {}",
+            config.alphabet.secret_name(),
+            match config.max_guesses {
+                Some(max_guesses) => format!("You have {max_guesses} guesses to get it."),
+                None => String::from("You have unlimited guesses to get it."),
+            }
+        )];
+
+        match config.time_limit {
+            Some(TimeLimit::PerGuess(limit)) => status.push(format!(
+                "You have {} seconds for each guess.",
+                limit.as_secs()
+            )),
+            Some(TimeLimit::PerGame(limit)) => {
+                status.push(format!("You have {} seconds to find it.", limit.as_secs()))
+            }
+            None => {}
+        }
+
+        Self {
+            config,
+            secret_num: secret_num.to_owned(),
+            title: None,
+            board: Board::new(config),
+            status,
+            num_guesses: 1,
+            previous_guesses: vec![],
+            clue_history: vec![],
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Sets the line shown above the messages under the board.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// How many guesses have been used so far.
+    pub fn guesses_used(&self) -> u32 {
+        self.num_guesses - 1
+    }
+
+    /// Draws the board with the pending messages.
+    pub fn draw(&mut self, term: &Term) -> Result<()> {
+        self.board.draw(term)?;
+
+        if let Some(title) = &self.title {
+            println!("{title}");
+        }
+
+        for message in self.status.drain(..) {
+            println!("{message}");
+        }
+
+        Ok(())
+    }
+
+    /// Plays until one guess has been used up. Returns the outcome once the
+    /// round is over.
+    pub fn take_turn(&mut self, term: &Term) -> Result<Option<Outcome>> {
+        let config = self.config;
+        // The countdown runs for the whole guess, so hints, marks and rejected
+        // guesses don't restart it.
+        let started = Instant::now();
+        let elapsed_before = self.elapsed;
+        let deadline = match config.time_limit {
+            Some(TimeLimit::PerGuess(limit)) => Some(started + limit),
+            Some(TimeLimit::PerGame(limit)) => Some(started + limit.saturating_sub(elapsed_before)),
+            None => None,
+        };
+
+        loop {
+            self.draw(term)?;
+
+            if config
+                .max_guesses
+                .is_some_and(|max_guesses| self.num_guesses > max_guesses)
+            {
+                println!("You ran out of guesses.");
+                println!("The answer was {}.", self.secret_num);
+                return Ok(Some(Outcome::Lost));
+            }
+
+            match deadline {
+                Some(deadline) => println!(
+                    "Guess #{} ({}s left): ",
+                    self.num_guesses,
+                    deadline.saturating_duration_since(Instant::now()).as_secs()
+                ),
+                None => println!("Guess #{}: ", self.num_guesses),
+            }
+
+            let input = match deadline {
+                Some(deadline) => input::read_line_before(deadline)?,
+                None => input::read_line()?.map_or(Timed::Closed, Timed::Line),
+            };
+            self.elapsed = elapsed_before + started.elapsed();
+
+            let guess = match input {
+                Timed::Line(guess) => guess,
+                Timed::Expired => return Ok(self.time_up()),
+                // The input was closed so there is nobody left to play.
+                Timed::Closed => return Ok(Some(Outcome::Quit)),
+            };
+
+            if let Some(marked) = board::apply_mark(&guess, &mut self.board.notepad, config) {
+                if let Err(error) = marked {
+                    self.status.push(error.to_string());
+                }
+
+                continue;
+            }
+
+            if guess.eq_ignore_ascii_case("hint") {
+                self.status.extend(hint(config, &self.clue_history));
+                continue;
+            }
+
+            let guess = guess.to_uppercase();
+
+            if let Err(error) = validate_guess(&guess, config, &self.previous_guesses) {
+                self.status.push(error.to_string());
+
+                if config.hard_mode {
+                    self.status
+                        .push(String::from("Hard mode: that still counts as a guess."));
+                    self.num_guesses += 1;
+                    return Ok(None);
+                }
+
+                continue;
+            }
+
+            let clue = get_clues(&guess, &self.secret_num);
+            self.board.record(&guess, clue);
+            self.clue_history.push((guess.clone(), clue));
+
+            if clue.is_solved(config.digits) {
+                self.draw(term)?;
+                println!("{}", config.language.solved());
+                return Ok(Some(Outcome::Won {
+                    guesses: self.num_guesses,
+                }));
+            }
+
+            self.num_guesses += 1;
+            self.previous_guesses.push(guess);
+            return Ok(None);
+        }
+    }
+
+    /// Handles a countdown running out, which loses the guess or the round.
+    fn time_up(&mut self) -> Option<Outcome> {
+        println!();
+
+        match self.config.time_limit {
+            Some(TimeLimit::PerGame(_)) => {
+                println!("Time's up!");
+                println!("The answer was {}.", self.secret_num);
+                Some(Outcome::Lost)
+            }
+            _ => {
+                self.status
+                    .push(String::from("Time's up! That guess was lost."));
+                self.num_guesses += 1;
+                None
+            }
+        }
+    }
+
+    /// Finishes the round with `outcome`.
+    pub fn into_round(self, outcome: Outcome) -> Round {
+        Round {
+            outcome,
+            clues: self.clue_history.iter().map(|(_, clue)| *clue).collect(),
+            elapsed: self.elapsed,
+        }
+    }
+}
+
+/// Plays one round where the player has to guess `secret_num`.
+pub fn play_round(config: &Config, term: &Term, secret_num: &str) -> Result<Round> {
+    let mut state = RoundState::new(config, secret_num);
+
+    loop {
+        if let Some(outcome) = state.take_turn(term)? {
+            let round = state.into_round(outcome);
+
+            if config.time_limit.is_some() {
+                if let Some(score) = round.score() {
+                    println!("Score: {score} ({} seconds).", round.elapsed.as_secs());
+                }
+            }
+
+            return Ok(round);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use std::fmt::Display;
+
+use crate::config::Config;
+
+/// The reasons a guess can be rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GuessError {
+    /// The guess does not have the configured number of symbols.
+    WrongLength {
+        expected: usize,
+        found: usize,
+        symbol: &'static str,
+    },
+    /// The guess contains symbols that are not in the alphabet.
+    InvalidSymbols {
+        symbols: Vec<char>,
+        allowed: &'static str,
+    },
+    /// The guess repeats symbols while repeats are not allowed.
+    RepeatedSymbols {
+        symbols: Vec<char>,
+        symbol: &'static str,
+    },
+    /// The exact same guess was already made this game.
+    AlreadyGuessed,
+}
+
+impl Display for GuessError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GuessError::WrongLength {
+                expected,
+                found,
+                symbol,
+            } => write!(
+                f,
+                "Your guess has {found} {symbol}s but it needs exactly {expected}."
+            ),
+            GuessError::InvalidSymbols { symbols, allowed } => write!(
+                f,
+                "{} {} not allowed. Use only {allowed}.",
+                join(symbols),
+                if symbols.len() == 1 { "is" } else { "are" }
+            ),
+            GuessError::RepeatedSymbols { symbols, symbol } => write!(
+                f,
+                "The secret has no repeated {symbol}s but your guess repeats {}.",
+                join(symbols)
+            ),
+            GuessError::AlreadyGuessed => write!(f, "You already tried that guess."),
+        }
+    }
+}
+
+impl std::error::Error for GuessError {}
+
+/// Checks that `guess` is a guess that can be scored for the current
+/// settings and has not been made before.
+pub fn validate_guess(
+    guess: &str,
+    config: &Config,
+    previous_guesses: &[String],
+) -> Result<(), GuessError> {
+    let symbol = config.alphabet.symbol_name();
+    let found = guess.chars().count();
+
+    if found != config.digits {
+        return Err(GuessError::WrongLength {
+            expected: config.digits,
+            found,
+            symbol,
+        });
+    }
+
+    let invalid = distinct(guess.chars().filter(|&c| !config.alphabet.contains(c)));
+
+    if !invalid.is_empty() {
+        return Err(GuessError::InvalidSymbols {
+            symbols: invalid,
+            allowed: config.alphabet.description(),
+        });
+    }
+
+    if !config.allow_repeats {
+        let repeated = distinct(
+            guess
+                .chars()
+                .filter(|&c| guess.chars().filter(|&other| other == c).count() > 1),
+        );
+
+        if !repeated.is_empty() {
+            return Err(GuessError::RepeatedSymbols {
+                symbols: repeated,
+                symbol,
+            });
+        }
+    }
+
+    if previous_guesses.iter().any(|previous| previous == guess) {
+        return Err(GuessError::AlreadyGuessed);
+    }
+
+    Ok(())
+}
+
+/// Collects the symbols in order of first appearance without duplicates.
+fn distinct(symbols: impl Iterator<Item = char>) -> Vec<char> {
+    let mut seen = vec![];
+
+    for symbol in symbols {
+        if !seen.contains(&symbol) {
+            seen.push(symbol);
+        }
+    }
+
+    seen
+}
+
+/// Formats symbols as a quoted, comma separated list.
+pub fn join(symbols: &[char]) -> String {
+    symbols
+        .iter()
+        .map(|symbol| format!("'{symbol}'"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,124 @@
+use anyhow::Result;
+use console::Term;
+
+use crate::{
+    config::Config,
+    game::{Outcome, RoundState},
+    get_secret_number,
+    input::{prompt, read_line_with},
+};
+
+/// Two players take turns guessing their own secrets on one terminal. The
+/// player who needs the fewest guesses wins.
+pub fn play(config: &Config, term: &Term) -> Result<()> {
+    print!("{}", config.intro());
+    println!("Hot-seat mode: two players each get their own secret and take turns");
+    println!("making one guess at a time. The fewest guesses wins.");
+    println!();
+
+    let mut names = vec![];
+    for player in 1..=2 {
+        let Some(name) = read_line_with(&format!("Name of player {player}: "))? else {
+            return Ok(());
+        };
+
+        names.push(if name.is_empty() {
+            format!("Player {player}")
+        } else {
+            name
+        });
+    }
+
+    let mut states: Vec<RoundState> = names
+        .iter()
+        .map(|name| {
+            RoundState::new(config, &get_secret_number(config))
+                .with_title(format!("{name}'s turn."))
+        })
+        .collect();
+    let mut outcomes: Vec<Option<Outcome>> = vec![None; states.len()];
+
+    'turns: loop {
+        for player in 0..states.len() {
+            if outcomes[player].is_some() {
+                continue;
+            }
+
+            // Nobody can beat a player who already solved it in fewer
+            // guesses than they have used.
+            let best = outcomes
+                .iter()
+                .filter_map(|outcome| match outcome {
+                    Some(Outcome::Won { guesses }) => Some(*guesses),
+                    _ => None,
+                })
+                .min();
+
+            if best.is_some_and(|best| states[player].guesses_used() >= best) {
+                outcomes[player] = Some(Outcome::Lost);
+                continue;
+            }
+
+            term.clear_screen()?;
+            prompt(&format!(
+                "Pass the keyboard to {}, then press Enter...",
+                names[player]
+            ))?;
+
+            let outcome = states[player].take_turn(term)?;
+
+            if outcome == Some(Outcome::Quit) {
+                break 'turns;
+            }
+
+            if outcome.is_none() {
+                states[player].draw(term)?;
+            }
+
+            outcomes[player] = outcome;
+            prompt("Press Enter to hide your board...")?;
+        }
+
+        if outcomes.iter().all(Option::is_some) {
+            break;
+        }
+    }
+
+    term.clear_screen()?;
+    println!("Results");
+    println!();
+
+    let width = names.iter().map(String::len).max().unwrap_or_default();
+    for (name, outcome) in names.iter().zip(&outcomes) {
+        let result = match outcome {
+            Some(Outcome::Won { guesses }) => format!("solved in {guesses} guesses"),
+            Some(Outcome::Lost) => String::from("did not solve it"),
+            Some(Outcome::Quit) | None => String::from("did not finish"),
+        };
+
+        println!("  {name:width$}  {result}");
+    }
+
+    println!();
+
+    let guesses: Vec<Option<u32>> = outcomes
+        .iter()
+        .map(|outcome| match outcome {
+            Some(Outcome::Won { guesses }) => Some(*guesses),
+            _ => None,
+        })
+        .collect();
+
+    match (guesses[0], guesses[1]) {
+        (Some(first), Some(second)) if first == second => println!("It's a draw!"),
+        (Some(first), Some(second)) => {
+            let winner = if first < second { 0 } else { 1 };
+            println!("{} wins!", names[winner]);
+        }
+        (Some(_), None) => println!("{} wins!", names[0]),
+        (None, Some(_)) => println!("{} wins!", names[1]),
+        (None, None) => println!("Nobody solved their secret."),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use std::{
+    io::Write,
+    sync::{
+        mpsc::{self, Receiver, RecvTimeoutError},
+        Mutex, OnceLock,
+    },
+    thread,
+    time::Instant,
+};
+
+use anyhow::{anyhow, Result};
+
+/// A line of input that had to arrive before a deadline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Timed {
+    Line(String),
+    Expired,
+    Closed,
+}
+
+/// The lines read from standard input by a background thread, so reading can
+/// stop waiting when a countdown runs out.
+fn lines() -> &'static Mutex<Receiver<String>> {
+    static LINES: OnceLock<Mutex<Receiver<String>>> = OnceLock::new();
+
+    LINES.get_or_init(|| {
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for line in std::io::stdin().lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+
+                if tx.send(line.trim().to_owned()).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Mutex::new(rx)
+    })
+}
+
+/// Prints `message` and reads a trimmed line of input.
+pub fn prompt(message: &str) -> Result<String> {
+    Ok(read_line_with(message)?.unwrap_or_default())
+}
+
+/// Prints `message` and reads a trimmed line of input, or `None` once the
+/// input is closed.
+pub fn read_line_with(message: &str) -> Result<Option<String>> {
+    print!("{message}");
+    std::io::stdout().flush()?;
+
+    read_line()
+}
+
+/// Reads a trimmed line of input, or `None` once the input is closed.
+pub fn read_line() -> Result<Option<String>> {
+    let lines = lines()
+        .lock()
+        .map_err(|_| anyhow!("The input was poisoned."))?;
+
+    Ok(lines.recv().ok())
+}
+
+/// Reads a trimmed line of input if one arrives before `deadline`.
+pub fn read_line_before(deadline: Instant) -> Result<Timed> {
+    let lines = lines()
+        .lock()
+        .map_err(|_| anyhow!("The input was poisoned."))?;
+
+    Ok(
+        match lines.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            Ok(line) => Timed::Line(line),
+            Err(RecvTimeoutError::Timeout) => Timed::Expired,
+            Err(RecvTimeoutError::Disconnected) => Timed::Closed,
+        },
+    )
+}
@@ -1,110 +1,331 @@
-const NUM_DIGITS: usize = 3;
-const MAX_GUESSES: i32 = 10;
-
-fn main() -> Result<(), std::io::Error> {
-    println!(
-        r#"
-Bagels, a deductive logic game.
-    
-I am thinking of a {NUM_DIGITS}-digit number with no repeated digits.
-Try to guess what it is. Here are some clues:
-When I say:     That means:
-Pico            One digit is correct but in the wrong position.
-Fermi           One digit is correct and in the right position.
-Bagels          No digit is correct.
-
-For example, if the secret number was 248 and your guess was 843, the
-clues would be Fermi Pico.
-    "#
-    );
+mod board;
+mod clue;
+mod config;
+mod daily;
+mod game;
+mod guess;
+mod hotseat;
+mod input;
+mod reverse;
+mod solver;
+mod stats;
+
+use anyhow::Result;
+use clue::Clue;
+use config::Config;
+use console::Term;
+use game::Outcome;
+use input::{prompt, read_line_with};
+use rand::seq::{IndexedRandom, SliceRandom};
+use solver::Solver;
+use stats::Stats;
+
+fn main() -> Result<()> {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("solve" | "reverse") => {
+            let config = Config::from_args(args.drain(1..))?;
+            return reverse::play(&config);
+        }
+        Some("daily") => {
+            let config = Config::from_args(args.drain(1..))?;
+            return daily::play(&config, &Term::stdout());
+        }
+        Some("hotseat") => {
+            let config = Config::from_args(args.drain(1..))?;
+            return hotseat::play(&config, &Term::stdout());
+        }
+        _ => {}
+    }
+
+    let mut config = Config::from_args(args)?;
+    let term = Term::stdout();
+
+    print!("{}", config.intro());
+    prompt("Press Enter to start...")?;
+
+    let mut stats = Stats::default();
 
     // Main game loop
     loop {
         // This stores the secret number the player needs to guess:
-        let secret_num = get_secret_number();
+        let secret_num = get_secret_number(&config);
+        let round = game::play_round(&config, &term, &secret_num)?;
+        stats.record(round.outcome);
 
-        println!("I have thought up a number.");
-        println!("You have {MAX_GUESSES} guesses to get it.");
+        if round.outcome == Outcome::Quit {
+            break;
+        }
 
-        let mut num_guesses = 1;
+        // Ask player if they want to play again.
+        let play_again = loop {
+            let Some(input) = read_line_with("Do you want to play again? (yes, no or options) ")?
+            else {
+                break false;
+            };
 
-        while num_guesses <= MAX_GUESSES {
-            let mut guess = String::new();
-            // Keep looping until they enter a valid guess:
-            while (guess.len() != NUM_DIGITS) || guess.parse::<u32>().is_err() {
-                println!("Guess #{num_guesses}: ");
+            if input.eq_ignore_ascii_case("options") {
+                edit_config(&mut config)?;
+                print!("{}", config.intro());
+                prompt("Press Enter to start...")?;
+                break true;
+            }
 
-                guess.clear();
-                std::io::stdin().read_line(&mut guess)?;
-                guess = guess.trim().to_owned();
+            match config::parse_yes_no(&input) {
+                Ok(play_again) => break play_again,
+                Err(error) => println!("{error}"),
             }
+        };
+
+        if !play_again {
+            break;
+        }
+    }
+
+    println!();
+    print!("{stats}");
+    println!("Thanks for playing!");
+    Ok(())
+}
+
+/// Describes how many secrets are still possible and which guess narrows
+/// them down the most.
+fn hint(config: &Config, clue_history: &[(String, Clue)]) -> Vec<String> {
+    let solver = match Solver::from_history(config, clue_history) {
+        Ok(solver) => solver,
+        Err(error) => return vec![error.to_string()],
+    };
+
+    let remaining = solver.candidates().len();
+    let mut lines = vec![match remaining {
+        1 => String::from("Only one secret is still possible."),
+        _ => format!("{remaining} secrets are still possible."),
+    }];
+
+    if let Some((guess, worst)) = solver.suggest() {
+        lines.push(format!(
+            "Try {guess}, it leaves at most about {worst} possibilities."
+        ));
+    }
 
-            let clues = get_clues(&guess, &secret_num);
-            println!("{}", clues.expect("There are no clues"));
-            num_guesses += 1;
+    lines
+}
+
+/// Lets the player change the settings between games. Leaving an answer
+/// empty keeps the current value.
+fn edit_config(config: &mut Config) -> Result<()> {
+    println!("Current settings: {config}.");
+
+    loop {
+        let input = prompt(&format!(
+            "Number length ({}-{}) [{}]: ",
+            config::MIN_DIGITS,
+            config::MAX_DIGITS,
+            config.digits
+        ))?;
+
+        if input.is_empty() {
+            break;
+        }
 
-            if guess == secret_num {
-                break; // They are correct so break out of the loop
+        match config::parse_digits(&input) {
+            Ok(digits) => {
+                config.digits = digits;
+                break;
             }
+            Err(error) => println!("{error}"),
+        }
+    }
+
+    loop {
+        let current = config
+            .max_guesses
+            .map_or(String::from("unlimited"), |max_guesses| {
+                max_guesses.to_string()
+            });
+        let input = prompt(&format!(
+            "Guess limit (a number or unlimited) [{current}]: "
+        ))?;
+
+        if input.is_empty() {
+            break;
+        }
 
-            if num_guesses > MAX_GUESSES {
-                println!("You ran out of guesses.");
-                println!("The answer was {secret_num}.");
+        match config::parse_max_guesses(&input) {
+            Ok(max_guesses) => {
+                config.max_guesses = max_guesses;
+                break;
             }
+            Err(error) => println!("{error}"),
         }
+    }
 
-        // Ask player if they want to play again.
-        println!("Do you want to play again? (yes or no)");
+    loop {
+        let input = prompt(&format!(
+            "Alphabet (decimal, hex or letters) [{}]: ",
+            config.alphabet
+        ))?;
+
+        if input.is_empty() {
+            break;
+        }
+
+        match input.parse() {
+            Ok(alphabet) => {
+                config.alphabet = alphabet;
+                break;
+            }
+            Err(error) => println!("{error}"),
+        }
+    }
 
-        let mut input = String::new();
-        std::io::stdin().read_line(&mut input)?;
+    loop {
+        let current = if config.allow_repeats { "yes" } else { "no" };
+        let input = prompt(&format!(
+            "Allow repeated {}s (yes or no) [{current}]: ",
+            config.alphabet.symbol_name()
+        ))?;
 
-        if input.to_lowercase().contains("yes") {
+        if input.is_empty() {
             break;
         }
+
+        match config::parse_yes_no(&input) {
+            Ok(allow_repeats) => {
+                config.allow_repeats = allow_repeats;
+                break;
+            }
+            Err(error) => println!("{error}"),
+        }
     }
 
-    Ok(println!("Thanks for playing!"))
-}
+    loop {
+        let current = if config.hard_mode { "yes" } else { "no" };
+        let input = prompt(&format!(
+            "Hard mode, invalid guesses cost a turn (yes or no) [{current}]: "
+        ))?;
+
+        if input.is_empty() {
+            break;
+        }
+
+        match config::parse_yes_no(&input) {
+            Ok(hard_mode) => {
+                config.hard_mode = hard_mode;
+                break;
+            }
+            Err(error) => println!("{error}"),
+        }
+    }
+
+    loop {
+        let input = prompt(&format!(
+            "Clue language (english, spanish or german) [{}]: ",
+            config.language
+        ))?;
+
+        if input.is_empty() {
+            break;
+        }
+
+        match input.parse() {
+            Ok(language) => {
+                config.language = language;
+                break;
+            }
+            Err(error) => println!("{error}"),
+        }
+    }
+
+    loop {
+        let current = if config.compact_clues { "yes" } else { "no" };
+        let input = prompt(&format!(
+            "Compact clues such as 2F 1P (yes or no) [{current}]: "
+        ))?;
 
-/// Returns a a number made up of a number unique random digits.
-fn get_secret_number() -> String {
-    format!("{0:0nums$}", rand::random::<usize>(), nums = NUM_DIGITS)
-        .chars()
-        .take(NUM_DIGITS)
-        .collect()
+        if input.is_empty() {
+            break;
+        }
+
+        match config::parse_yes_no(&input) {
+            Ok(compact_clues) => {
+                config.compact_clues = compact_clues;
+                break;
+            }
+            Err(error) => println!("{error}"),
+        }
+    }
+
+    loop {
+        let current = config
+            .time_limit
+            .map_or(String::from("none"), |time_limit| time_limit.to_string());
+        let input = prompt(&format!(
+            "Time limit (none, guess:<seconds> or game:<seconds>) [{current}]: "
+        ))?;
+
+        if input.is_empty() {
+            break;
+        }
+
+        match config::parse_time_limit(&input) {
+            Ok(time_limit) => {
+                config.time_limit = time_limit;
+                break;
+            }
+            Err(error) => println!("{error}"),
+        }
+    }
+
+    println!("New settings: {config}.");
+    Ok(())
 }
 
-/// Returns a string with pico, fermi, bagels clues for a guess and secret
-/// number pair
-fn get_clues(guess: &str, secret_num: &str) -> Option<String> {
-    if guess == secret_num {
-        return Some(String::from("You got it!"));
+/// Returns a secret number made up of `config.digits` random symbols, which
+/// are all different unless repeats are allowed.
+fn get_secret_number(config: &Config) -> String {
+    let mut symbols: Vec<char> = config.alphabet.symbols().chars().collect();
+    let mut rng = rand::rng();
+
+    if config.allow_repeats {
+        return (0..config.digits)
+            .filter_map(|_| symbols.choose(&mut rng))
+            .collect();
     }
 
-    let mut clues = vec![];
+    // Shuffling the whole alphabet gives every symbol the same chance of
+    // being in every position.
+    symbols.shuffle(&mut rng);
+    symbols.into_iter().take(config.digits).collect()
+}
+
+/// Returns the pico, fermi, bagels clue for a guess and secret number pair.
+fn get_clues(guess: &str, secret_num: &str) -> Clue {
+    let mut clue = Clue::default();
+    // How often each secret digit appears in the positions that did not
+    // match, so a repeated digit is never counted more than once. All
+    // alphabets are ASCII.
+    let mut unmatched = [0u8; 128];
 
-    for i in 0..guess.len() {
-        if guess.chars().nth(i)? == secret_num.chars().nth(i)? {
+    for (guess_digit, secret_digit) in guess.bytes().zip(secret_num.bytes()) {
+        if guess_digit == secret_digit {
             // A correct digit is in the correct place.
-            clues.push("Fermi");
-        } else if secret_num.contains(guess.chars().nth(i)?) {
-            // A correct digit is in the incorrect place.
-            clues.push("Pico");
+            clue.fermi += 1;
+        } else {
+            unmatched[usize::from(secret_digit & 0x7f)] += 1;
         }
     }
 
-    if clues.is_empty() {
-        // There are no correct digits at all
-        Some(String::from("Bagels"))
-    } else {
-        // Sort the clues into alphabetical order so their original order
-        // doesn't give information away.
-        clues.sort();
-        // Make single string from the list of string clues.
-        Some(clues.iter().fold(String::new(), |mut init, val| {
-            init.push_str(val);
-            init
-        }))
+    for (guess_digit, secret_digit) in guess.bytes().zip(secret_num.bytes()) {
+        let remaining = &mut unmatched[usize::from(guess_digit & 0x7f)];
+
+        if guess_digit != secret_digit && *remaining > 0 {
+            // A correct digit is in the incorrect place.
+            *remaining -= 1;
+            clue.pico += 1;
+        }
     }
+
+    clue
 }
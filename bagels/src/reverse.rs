@@ -0,0 +1,198 @@
+use anyhow::Result;
+
+use crate::{
+    clue::Clue,
+    config::Config,
+    get_clues,
+    guess::{join, validate_guess, GuessError},
+    input::read_line_with,
+    solver::{self, Solver},
+};
+
+/// Plays the game the other way around: the player thinks of a secret and
+/// answers the computer's guesses with clues. Every guess the computer makes
+/// is consistent with all the answers given so far.
+pub fn play(config: &Config) -> Result<()> {
+    // Fails for settings with too many possible secrets, so it comes before
+    // the introduction.
+    let mut solver = Solver::new(config)?;
+    let symbol = config.alphabet.symbol_name();
+    let secret = config.alphabet.secret_name();
+
+    println!("Bagels, the computer guesses.");
+    println!();
+    println!(
+        "Think of a {}-{symbol} {secret} made of {}{}.",
+        config.digits,
+        config.alphabet.description(),
+        if config.allow_repeats {
+            String::new()
+        } else {
+            format!(", with no repeated {symbol}s")
+        }
+    );
+    let language = config.language;
+    println!(
+        "Answer each of my guesses with {}, {} or {} (e.g. `{}`",
+        language.fermi(),
+        language.pico(),
+        language.bagels(),
+        language.render(Clue { fermi: 1, pico: 1 }, false)
+    );
+    println!(
+        "or `{}`), or `correct` when I have guessed it.",
+        language.render(Clue { fermi: 1, pico: 1 }, true)
+    );
+    println!();
+
+    let mut history: Vec<(String, Clue)> = vec![];
+
+    loop {
+        let num_guesses = history.len() + 1;
+
+        if config
+            .max_guesses
+            .is_some_and(|max_guesses| num_guesses > max_guesses as usize)
+        {
+            println!("I ran out of guesses. You win!");
+            break;
+        }
+
+        let Some((guess, _)) = solver.suggest() else {
+            explain_contradiction(config, &history)?;
+            break;
+        };
+
+        let Some(clue) = read_clue(&format!("Guess #{num_guesses}: {guess}? "), config)? else {
+            println!();
+            break;
+        };
+
+        if clue.is_solved(config.digits) {
+            println!("I got it in {num_guesses} guesses!");
+            break;
+        }
+
+        solver.record(&guess, clue);
+        history.push((guess, clue));
+    }
+
+    println!("Thanks for playing!");
+    Ok(())
+}
+
+/// Asks for clues until the player gives a valid answer. Returns `None` once
+/// the input is closed.
+fn read_clue(message: &str, config: &Config) -> Result<Option<Clue>> {
+    loop {
+        let Some(answer) = read_line_with(message)? else {
+            return Ok(None);
+        };
+
+        if matches!(
+            answer.to_lowercase().as_str(),
+            "correct" | "yes" | "you got it" | "you got it!"
+        ) {
+            return Ok(Some(Clue::solved(config.digits)));
+        }
+
+        match config.language.parse(&answer) {
+            Ok(clue) if clue.total() <= config.digits => return Ok(Some(clue)),
+            Ok(_) => println!(
+                "There can't be more clues than the {} {}s of my guess.",
+                config.digits,
+                config.alphabet.symbol_name()
+            ),
+            Err(error) => println!("{error}"),
+        }
+    }
+}
+
+/// Tells the player which of their answers can't all be true, and if they
+/// reveal their secret, exactly which answers were wrong.
+fn explain_contradiction(config: &Config, history: &[(String, Clue)]) -> Result<()> {
+    let secret = config.alphabet.secret_name();
+
+    println!("No {secret} matches all of your answers.");
+
+    match solver::inconsistent_answers(config, history)?.as_slice() {
+        [] => println!("More than one of your answers must be wrong."),
+        [single] => {
+            let (guess, clue) = &history[*single];
+            println!(
+                "Your answer to guess #{} ({guess}: {}) contradicts the others.",
+                single + 1,
+                config.language.render(*clue, config.compact_clues)
+            );
+        }
+        suspects => {
+            println!("Any one of these answers could be the wrong one:");
+            for &i in suspects {
+                let (guess, clue) = &history[i];
+                println!(
+                    "  Guess #{} ({guess}): {}",
+                    i + 1,
+                    config.language.render(*clue, config.compact_clues)
+                );
+            }
+        }
+    }
+
+    loop {
+        let Some(input) =
+            read_line_with(&format!("What was your {secret}? (press Enter to skip) "))?
+        else {
+            println!();
+            return Ok(());
+        };
+
+        if input.is_empty() {
+            return Ok(());
+        }
+
+        let actual = input.to_uppercase();
+
+        if let Err(error) = validate_guess(&actual, config, &[]) {
+            println!(
+                "That can't be your {secret}. {}",
+                secret_error(&error, config)
+            );
+            continue;
+        }
+
+        for (i, (guess, clue)) in history.iter().enumerate() {
+            let expected = get_clues(guess, &actual);
+
+            if expected != *clue {
+                println!(
+                    "For guess #{} ({guess}) you said {} but it should have been {}.",
+                    i + 1,
+                    config.language.render(*clue, config.compact_clues),
+                    config.language.render(expected, config.compact_clues)
+                );
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+/// Explains why a revealed secret was rejected, worded for the player's
+/// secret rather than a guess.
+fn secret_error(error: &GuessError, config: &Config) -> String {
+    let secret = config.alphabet.secret_name();
+
+    match error {
+        GuessError::WrongLength {
+            expected,
+            found,
+            symbol,
+        } => format!("It has {found} {symbol}s but your {secret} needs exactly {expected}."),
+        GuessError::RepeatedSymbols { symbols, symbol } => format!(
+            "Your {secret} can't repeat {symbol}s but {} {} repeated.",
+            join(symbols),
+            if symbols.len() == 1 { "is" } else { "are" }
+        ),
+        GuessError::InvalidSymbols { .. } | GuessError::AlreadyGuessed => error.to_string(),
+    }
+}
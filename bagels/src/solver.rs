@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use rand::seq::IndexedRandom;
+
+use crate::{clue::Clue, config::Config, get_clues};
+
+/// The largest number of possible secrets the solver will enumerate.
+const MAX_SPACE: usize = 1_000_000;
+/// How many guesses are compared when looking for the best suggestion.
+const MAX_GUESS_POOL: usize = 200;
+/// How many candidates each compared guess is scored against.
+const MAX_SCORED_CANDIDATES: usize = 2_000;
+
+/// Keeps track of every secret that is still consistent with the clues seen
+/// so far.
+pub struct Solver {
+    candidates: Vec<String>,
+}
+
+impl Solver {
+    /// Creates a [`Solver`] with every possible secret for `config`.
+    pub fn new(config: &Config) -> Result<Self> {
+        let symbols: Vec<char> = config.alphabet.symbols().chars().collect();
+        let space = space_size(symbols.len(), config.digits, config.allow_repeats);
+
+        if space.is_none_or(|space| space > MAX_SPACE) {
+            return Err(anyhow!(
+                "There are too many possible secrets for the solver, use a shorter {} or a smaller alphabet.",
+                config.alphabet.secret_name()
+            ));
+        }
+
+        let mut candidates = vec![];
+        let mut current = String::with_capacity(config.digits);
+        enumerate(
+            &symbols,
+            config.digits,
+            config.allow_repeats,
+            &mut current,
+            &mut candidates,
+        );
+
+        Ok(Self { candidates })
+    }
+
+    /// Creates a [`Solver`] that has already seen every clue in `history`.
+    pub fn from_history(config: &Config, history: &[(String, Clue)]) -> Result<Self> {
+        let mut solver = Solver::new(config)?;
+
+        for (guess, clue) in history {
+            solver.record(guess, *clue);
+        }
+
+        Ok(solver)
+    }
+
+    /// Removes every candidate that would not have produced `clue` for
+    /// `guess`.
+    pub fn record(&mut self, guess: &str, clue: Clue) {
+        self.candidates
+            .retain(|candidate| get_clues(guess, candidate) == clue);
+    }
+
+    /// The secrets that are still possible.
+    pub fn candidates(&self) -> &[String] {
+        &self.candidates
+    }
+
+    /// Suggests the candidate that leaves the fewest candidates in the worst
+    /// case, along with that worst case count. When there are many candidates
+    /// only a random sample is compared, so the count is an estimate.
+    pub fn suggest(&self) -> Option<(String, usize)> {
+        let mut rng = rand::rng();
+        let pool = sample(&self.candidates, MAX_GUESS_POOL, &mut rng);
+        let scored = sample(&self.candidates, MAX_SCORED_CANDIDATES, &mut rng);
+        let scale = self.candidates.len() as f64 / scored.len() as f64;
+
+        pool.into_iter()
+            .map(|guess| {
+                let mut partitions: HashMap<Clue, usize> = HashMap::new();
+
+                for candidate in &scored {
+                    // The guess itself being the secret ends the game.
+                    if candidate != &guess {
+                        *partitions.entry(get_clues(guess, candidate)).or_default() += 1;
+                    }
+                }
+
+                let worst = partitions.into_values().max().unwrap_or_default();
+                (guess, (worst as f64 * scale).ceil() as usize)
+            })
+            .min_by_key(|(guess, worst)| (*worst, guess.as_str()))
+            .map(|(guess, worst)| (guess.clone(), worst))
+    }
+}
+
+/// Finds the answers in `history` that contradict the others, i.e. the ones
+/// that leave at least one possible secret when they are left out. Returns
+/// their indices, or nothing if more than one answer must be wrong.
+pub fn inconsistent_answers(config: &Config, history: &[(String, Clue)]) -> Result<Vec<usize>> {
+    let mut suspects = vec![];
+
+    for skipped in 0..history.len() {
+        let mut solver = Solver::new(config)?;
+
+        for (i, (guess, clue)) in history.iter().enumerate() {
+            if i != skipped {
+                solver.record(guess, *clue);
+            }
+        }
+
+        if !solver.candidates().is_empty() {
+            suspects.push(skipped);
+        }
+    }
+
+    Ok(suspects)
+}
+
+/// How many secrets of `digits` symbols can be made from `symbols` symbols.
+fn space_size(symbols: usize, digits: usize, allow_repeats: bool) -> Option<usize> {
+    if allow_repeats {
+        symbols.checked_pow(digits as u32)
+    } else {
+        (0..digits).try_fold(1usize, |space, i| {
+            space.checked_mul(symbols.checked_sub(i)?)
+        })
+    }
+}
+
+/// Pushes every secret that starts with `current` onto `candidates`.
+fn enumerate(
+    symbols: &[char],
+    digits: usize,
+    allow_repeats: bool,
+    current: &mut String,
+    candidates: &mut Vec<String>,
+) {
+    if current.len() == digits {
+        candidates.push(current.clone());
+        return;
+    }
+
+    for &symbol in symbols {
+        if allow_repeats || !current.contains(symbol) {
+            current.push(symbol);
+            enumerate(symbols, digits, allow_repeats, current, candidates);
+            current.pop();
+        }
+    }
+}
+
+/// Picks up to `amount` random items, or all of them if there are few enough.
+fn sample<'a>(items: &'a [String], amount: usize, rng: &mut impl rand::Rng) -> Vec<&'a String> {
+    if items.len() <= amount {
+        items.iter().collect()
+    } else {
+        items.choose_multiple(rng, amount).collect()
+    }
+}
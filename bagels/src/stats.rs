@@ -0,0 +1,69 @@
+use std::fmt::Display;
+
+use crate::game::Outcome;
+
+/// The results of every round played in one session.
+#[derive(Debug, Default)]
+pub struct Stats {
+    played: u32,
+    /// How many guesses each won round took.
+    wins: Vec<u32>,
+}
+
+impl Stats {
+    /// Adds the result of a round. Rounds that were quit are not counted.
+    pub fn record(&mut self, outcome: Outcome) {
+        match outcome {
+            Outcome::Won { guesses } => {
+                self.played += 1;
+                self.wins.push(guesses);
+            }
+            Outcome::Lost => self.played += 1,
+            Outcome::Quit => {}
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Games played: {}", self.played)?;
+
+        if self.played == 0 {
+            return Ok(());
+        }
+
+        let won = self.wins.len() as u32;
+        writeln!(
+            f,
+            "Games won: {won} ({}%)",
+            (won * 100 + self.played / 2) / self.played
+        )?;
+
+        let (Some(&best), Some(&worst)) = (self.wins.iter().min(), self.wins.iter().max()) else {
+            return Ok(());
+        };
+
+        let average = self.wins.iter().sum::<u32>() as f64 / self.wins.len() as f64;
+        writeln!(f, "Average guesses to solve: {average:.1}")?;
+        writeln!(
+            f,
+            "Best game: {best} {}",
+            if best == 1 { "guess" } else { "guesses" }
+        )?;
+
+        writeln!(f, "Guesses to solve:")?;
+        let width = worst.to_string().len();
+
+        for guesses in best..=worst {
+            let count = self.wins.iter().filter(|&&won| won == guesses).count();
+            writeln!(f, "  {guesses:>width$} | {} {count}", "#".repeat(count))?;
+        }
+
+        let lost = (self.played - won) as usize;
+        if lost > 0 {
+            writeln!(f, "  {:>width$} | {} {lost}", "X", "#".repeat(lost))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::{anyhow, Result};
+
+use crate::rival::Strategy;
+
+/// The most dice that can be rolled at once.
+pub const MAX_DICE: u32 = 10;
+
+/// The dice a game can be played with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Die {
+    D4,
+    #[default]
+    D6,
+    D8,
+    D10,
+    D12,
+    D20,
+}
+
+impl Die {
+    /// How many sides the die has.
+    pub fn sides(&self) -> u32 {
+        match self {
+            Die::D4 => 4,
+            Die::D6 => 6,
+            Die::D8 => 8,
+            Die::D10 => 10,
+            Die::D12 => 12,
+            Die::D20 => 20,
+        }
+    }
+}
+
+impl Display for Die {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "d{}", self.sides())
+    }
+}
+
+impl FromStr for Die {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let sides = s.trim().trim_start_matches(['d', 'D']);
+
+        match sides {
+            "4" => Ok(Die::D4),
+            "6" => Ok(Die::D6),
+            "8" => Ok(Die::D8),
+            "10" => Ok(Die::D10),
+            "12" => Ok(Die::D12),
+            "20" => Ok(Die::D20),
+            _ => Err(anyhow!(
+                "Unknown die `{s}`. Use d4, d6, d8, d10, d12 or d20."
+            )),
+        }
+    }
+}
+
+/// The settings a game is played with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// How many rounds are played.
+    pub rounds: u32,
+    /// The kind of die that is rolled.
+    pub die: Die,
+    /// How many dice each player rolls per round.
+    pub dice: u32,
+    /// The Rival to play against, or `None` for a random one.
+    pub rival: Option<Strategy>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            rounds: 10,
+            die: Die::default(),
+            dice: 1,
+            rival: None,
+        }
+    }
+}
+
+impl Config {
+    /// Builds a [`Config`] from command line arguments such as
+    /// `--rounds 5`, `--die d20`, `--dice 3` and `--rival greedy`.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        let mut config = Config::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| anyhow!("Missing value for `{arg}`."))
+            };
+
+            match arg.as_str() {
+                "--rounds" | "-r" => config.rounds = parse_count(&value()?, "rounds", u32::MAX)?,
+                "--die" | "-d" => config.die = value()?.parse()?,
+                "--dice" | "-n" => config.dice = parse_count(&value()?, "dice", MAX_DICE)?,
+                "--rival" => config.rival = Some(value()?.parse()?),
+                _ => return Err(anyhow!("Unknown argument `{arg}`.")),
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Describes the game that is played with these settings.
+    pub fn intro(&self) -> String {
+        let rounds = if self.rounds == 1 {
+            String::from("1 round")
+        } else {
+            format!("{} rounds", self.rounds)
+        };
+        let (roll, value) = if self.dice == 1 {
+            (format!("a {}-sided die", self.die.sides()), "dice value")
+        } else {
+            (
+                format!("{} {}-sided dice", self.dice, self.die.sides()),
+                "total",
+            )
+        };
+
+        let text = format!(
+            "In this game you and a computer Rival will play {rounds} where you will each \
+             roll {roll}, and the player with the highest {value} will win the round. The \
+             player who wins the most rounds wins the game. Good luck!"
+        );
+
+        wrap(&text, 60)
+    }
+}
+
+/// Parses a count between 1 and `max`.
+fn parse_count(input: &str, name: &str, max: u32) -> Result<u32> {
+    input
+        .trim()
+        .parse()
+        .ok()
+        .filter(|count| (1..=max).contains(count))
+        .ok_or_else(|| anyhow!("The number of {name} must be between 1 and {max}, not `{input}`."))
+}
+
+/// Breaks `text` into lines of at most `width` characters.
+pub fn wrap(text: &str, width: usize) -> String {
+    let mut lines = vec![String::new()];
+
+    for word in text.split_whitespace() {
+        let line = lines.last_mut().expect("there is always a line");
+
+        if !line.is_empty() && line.len() + 1 + word.len() > width {
+            lines.push(word.to_string());
+        } else {
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+    }
+
+    lines.join("\n") + "\n"
+}
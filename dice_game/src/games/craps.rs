@@ -0,0 +1,114 @@
+use anyhow::Result;
+use rand::rngs::ThreadRng;
+
+use super::{d6, Outcome, Rules};
+use crate::{
+    config::{wrap, Die},
+    input::{wait_for_key, Key},
+    render::glyph,
+};
+
+/// You play pass line bets against the Rival, who is the house. Each round
+/// is won on a come-out roll of 7 or 11, or by rolling the point again
+/// before a 7.
+#[derive(Debug)]
+pub struct Craps {
+    rounds: u32,
+}
+
+impl Craps {
+    pub fn new(rounds: u32) -> Self {
+        Self { rounds }
+    }
+}
+
+impl Rules for Craps {
+    fn intro(&self) -> String {
+        wrap(
+            &format!(
+                "Craps: you bet against the Rival, who is the house, for {} rounds. \
+                 Roll two dice. A 7 or 11 wins straight away and 2, 3 or 12 loses. \
+                 Any other total becomes your point, and you keep rolling until you \
+                 roll the point again to win, or a 7 to lose.",
+                self.rounds
+            ),
+            60,
+        )
+    }
+
+    fn play(&mut self, rng: &mut ThreadRng) -> Result<Outcome> {
+        let (mut player, mut house) = (0, 0);
+
+        for round in 1..=self.rounds {
+            println!();
+            println!("Round {round}");
+
+            let Some(won) = play_round(rng)? else {
+                return Ok(Outcome::Quit);
+            };
+
+            if won {
+                player += 1;
+                println!("You win this round.");
+            } else {
+                house += 1;
+                println!("The house wins this round.");
+            }
+
+            println!("The score is now - You : {player}. Rival : {house}.");
+        }
+
+        Ok(match player.cmp(&house) {
+            std::cmp::Ordering::Less => Outcome::Lost,
+            std::cmp::Ordering::Equal => Outcome::Draw,
+            std::cmp::Ordering::Greater => Outcome::Won,
+        })
+    }
+}
+
+/// Rolls until the round is decided. Returns whether the player won, or
+/// `None` if the player quits.
+fn play_round(rng: &mut ThreadRng) -> Result<Option<bool>> {
+    let Some(come_out) = roll(rng, "Press any key for the come-out roll...")? else {
+        return Ok(None);
+    };
+
+    let point = match come_out {
+        7 | 11 => return Ok(Some(true)),
+        2 | 3 | 12 => return Ok(Some(false)),
+        point => point,
+    };
+
+    println!("Your point is {point}.");
+
+    loop {
+        let Some(total) = roll(rng, "Press any key to roll again...")? else {
+            return Ok(None);
+        };
+
+        if total == point {
+            return Ok(Some(true));
+        }
+        if total == 7 {
+            return Ok(Some(false));
+        }
+    }
+}
+
+/// Waits for the player, then rolls two dice and returns their total, or
+/// `None` if the player quits.
+fn roll(rng: &mut ThreadRng, prompt: &str) -> Result<Option<u32>> {
+    if wait_for_key(prompt)? == Key::Quit {
+        return Ok(None);
+    }
+
+    let (first, second) = (d6(rng), d6(rng));
+    println!(
+        "You rolled {} {} = {}",
+        glyph(Die::D6, first),
+        glyph(Die::D6, second),
+        first + second
+    );
+
+    Ok(Some(first + second))
+}
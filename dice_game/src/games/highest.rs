@@ -0,0 +1,106 @@
+use std::cmp::Ordering;
+
+use anyhow::Result;
+use rand::{rngs::ThreadRng, Rng};
+
+use super::{Outcome, Rules};
+use crate::{
+    config::Config,
+    input::{wait_for_key, Key},
+    render,
+};
+
+/// Both players roll the configured dice and the highest total wins the
+/// round. Whoever wins the most rounds wins the game.
+#[derive(Debug)]
+pub struct Highest {
+    config: Config,
+}
+
+impl Highest {
+    pub fn new(config: Config) -> Self {
+        Self { config }
+    }
+
+    /// Rolls the configured dice and returns each die.
+    fn roll(&self, rng: &mut impl Rng) -> Vec<u32> {
+        (0..self.config.dice)
+            .map(|_| rng.random_range(1..=self.config.die.sides()))
+            .collect()
+    }
+}
+
+impl Rules for Highest {
+    fn intro(&self) -> String {
+        self.config.intro()
+    }
+
+    fn play(&mut self, rng: &mut ThreadRng) -> Result<Outcome> {
+        let config = self.config;
+        let mut player_points = 0;
+        let mut rival_points = 0;
+        let mut rounds_played = 0;
+
+        'rounds: for i in 0..config.rounds {
+            println!("Round {}", i + 1);
+
+            let rival_dice = self.roll(rng);
+            println!("Rival rolled {}", render::describe(config.die, &rival_dice));
+
+            if wait_for_key("Press any key to roll the dice...")? == Key::Quit {
+                break 'rounds;
+            }
+
+            let player_dice = self.roll(rng);
+            println!();
+            render::roll_animation(&config, &player_dice, &rival_dice, rng)?;
+            println!();
+
+            match player_dice
+                .iter()
+                .sum::<u32>()
+                .cmp(&rival_dice.iter().sum())
+            {
+                Ordering::Less => {
+                    rival_points += 1;
+                    println!("The Rival won this round.");
+                }
+                Ordering::Equal => println!("This round is a draw."),
+                Ordering::Greater => {
+                    player_points += 1;
+                    println!("You won this round.");
+                }
+            }
+
+            println!("The score is now - You : {player_points}. Rival : {rival_points}.");
+            rounds_played += 1;
+
+            if rounds_played < config.rounds
+                && wait_for_key("Press any key to continue...")? == Key::Quit
+            {
+                break 'rounds;
+            }
+
+            println!();
+        }
+
+        if rounds_played < config.rounds {
+            println!();
+            println!(
+                "You stopped after {rounds_played} of {} rounds.",
+                config.rounds
+            );
+            println!("The score was - You : {player_points}. Rival : {rival_points}.");
+            return Ok(Outcome::Quit);
+        }
+
+        println!("Game over.");
+        println!("The score is now - You : {player_points}. Rival : {rival_points}.");
+
+        Ok(match player_points.cmp(&rival_points) {
+            Ordering::Less => Outcome::Lost,
+            Ordering::Equal => Outcome::Draw,
+            Ordering::Greater => Outcome::Won,
+        })
+    }
+}
@@ -0,0 +1,357 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use rand::{rngs::ThreadRng, seq::IndexedRandom, Rng};
+
+use super::{d6, Outcome, Rules};
+use crate::{
+    config::{wrap, Die},
+    input::{read_line, wait_for_key, Key},
+    render::glyph,
+    rival::Strategy,
+};
+
+/// How many dice each player starts with.
+const STARTING_DICE: usize = 5;
+
+/// A claim that there are at least `quantity` dice showing `face` among all
+/// the dice on the table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Bid {
+    quantity: usize,
+    face: u32,
+}
+
+impl Bid {
+    /// Whether this bid is higher than `other`: more dice, or as many dice
+    /// with a higher face.
+    fn beats(&self, other: &Bid) -> bool {
+        (self.quantity, self.face) > (other.quantity, other.face)
+    }
+}
+
+impl Display for Bid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} × {}", self.quantity, glyph(Die::D6, self.face))
+    }
+}
+
+/// What a player does on their turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Move {
+    Raise(Bid),
+    /// Calls the last bid a lie.
+    Challenge,
+}
+
+/// Both players roll hidden dice and take turns raising a bid about all the
+/// dice on the table, until one calls the other a liar. Whoever is wrong
+/// loses a die, and the last player with dice wins.
+#[derive(Debug)]
+pub struct Liars {
+    rival: Strategy,
+}
+
+impl Liars {
+    pub fn new(rival: Strategy) -> Self {
+        Self { rival }
+    }
+}
+
+impl Rules for Liars {
+    fn intro(&self) -> String {
+        wrap(
+            &format!(
+                "Liar's Dice: you and the Rival each roll {STARTING_DICE} hidden dice. Take \
+                 turns bidding how many dice on the whole table show a face, like \
+                 \"3 5\" for three fives. Every bid must have more dice, or as many \
+                 dice with a higher face. Instead of raising you can call \"liar\". If \
+                 the bid was true the challenger loses a die, otherwise the bidder \
+                 does. The last player with dice wins."
+            ),
+            60,
+        )
+    }
+
+    fn play(&mut self, rng: &mut ThreadRng) -> Result<Outcome> {
+        let (mut player_dice, mut rival_dice) = (STARTING_DICE, STARTING_DICE);
+        let mut player_starts = true;
+
+        while player_dice > 0 && rival_dice > 0 {
+            println!();
+            println!("You have {player_dice} dice, the Rival has {rival_dice}.");
+
+            let player: Vec<u32> = (0..player_dice).map(|_| d6(rng)).collect();
+            let rival: Vec<u32> = (0..rival_dice).map(|_| d6(rng)).collect();
+            println!("Your dice: {}", faces(&player));
+
+            let Some(player_lost) = play_round(self.rival, &player, &rival, player_starts, rng)?
+            else {
+                return Ok(Outcome::Quit);
+            };
+
+            if player_lost {
+                player_dice -= 1;
+                println!("You lose a die.");
+            } else {
+                rival_dice -= 1;
+                println!("The Rival loses a die.");
+            }
+
+            // The loser of a round opens the next one.
+            player_starts = player_lost;
+
+            if player_dice > 0
+                && rival_dice > 0
+                && wait_for_key("Press any key for the next round...")? == Key::Quit
+            {
+                return Ok(Outcome::Quit);
+            }
+        }
+
+        Ok(if player_dice > 0 {
+            Outcome::Won
+        } else {
+            Outcome::Lost
+        })
+    }
+
+    fn simulate(&self, players: [Strategy; 2], rng: &mut ThreadRng) -> Option<Outcome> {
+        let mut counts = [STARTING_DICE, STARTING_DICE];
+        let mut turn = 0;
+
+        while counts.iter().all(|&count| count > 0) {
+            let dice: Vec<Vec<u32>> = counts
+                .iter()
+                .map(|&count| (0..count).map(|_| d6(rng)).collect())
+                .collect();
+            let mut bid = None;
+
+            let loser = loop {
+                let (me, other) = (turn % 2, (turn + 1) % 2);
+
+                match rival_move(players[me], &dice[me], counts[other], bid, rng) {
+                    Move::Raise(raise) => bid = Some(raise),
+                    Move::Challenge => {
+                        let last = bid.expect("only a bid can be challenged");
+                        break if is_true(last, &dice[0], &dice[1]) {
+                            me
+                        } else {
+                            other
+                        };
+                    }
+                }
+
+                turn += 1;
+            };
+
+            counts[loser] -= 1;
+            // The loser of a round opens the next one.
+            turn = loser;
+        }
+
+        Some(if counts[0] > 0 {
+            Outcome::Won
+        } else {
+            Outcome::Lost
+        })
+    }
+}
+
+/// Whether there are at least as many dice showing the bid's face as it
+/// claims.
+fn is_true(bid: Bid, first: &[u32], second: &[u32]) -> bool {
+    first
+        .iter()
+        .chain(second)
+        .filter(|&&die| die == bid.face)
+        .count()
+        >= bid.quantity
+}
+
+/// Plays bids until someone is challenged. Returns whether the player lost
+/// the round, or `None` if the player quits.
+fn play_round(
+    strategy: Strategy,
+    player: &[u32],
+    rival: &[u32],
+    player_starts: bool,
+    rng: &mut ThreadRng,
+) -> Result<Option<bool>> {
+    let mut bid: Option<Bid> = None;
+    let mut players_turn = player_starts;
+    let total = player.len() + rival.len();
+
+    loop {
+        let action = if players_turn {
+            match read_move(bid, total)? {
+                Some(action) => action,
+                None => return Ok(None),
+            }
+        } else {
+            let action = rival_move(strategy, rival, player.len(), bid, rng);
+            match action {
+                Move::Raise(bid) => println!("The Rival bids {bid}."),
+                Move::Challenge => println!("The Rival calls you a liar!"),
+            }
+            action
+        };
+
+        match (action, bid) {
+            (Move::Raise(raise), _) => bid = Some(raise),
+            (Move::Challenge, Some(last)) => {
+                let count = player
+                    .iter()
+                    .chain(rival)
+                    .filter(|&&die| die == last.face)
+                    .count();
+
+                println!("The Rival's dice: {}", faces(rival));
+                println!(
+                    "There {} {count} × {} on the table.",
+                    if count == 1 { "is" } else { "are" },
+                    glyph(Die::D6, last.face)
+                );
+
+                // The challenger loses if the bid was true.
+                let challenger_lost = is_true(last, player, rival);
+                let player_lost = if players_turn {
+                    challenger_lost
+                } else {
+                    !challenger_lost
+                };
+
+                return Ok(Some(player_lost));
+            }
+            (Move::Challenge, None) => unreachable!("there is no bid to challenge yet"),
+        }
+
+        players_turn = !players_turn;
+    }
+}
+
+/// Asks the player for a bid, or for a challenge if there is a bid. Returns
+/// `None` if the input was closed.
+fn read_move(bid: Option<Bid>, total: usize) -> Result<Option<Move>> {
+    loop {
+        let prompt = if bid.is_some() {
+            "Your bid (e.g. `3 5`), or `liar`: "
+        } else {
+            "Your opening bid (e.g. `3 5`): "
+        };
+        let Some(input) = read_line(prompt)? else {
+            return Ok(None);
+        };
+
+        if input.eq_ignore_ascii_case("liar") {
+            if bid.is_some() {
+                return Ok(Some(Move::Challenge));
+            }
+            println!("There is no bid to challenge yet.");
+            continue;
+        }
+
+        let numbers: Vec<usize> = input
+            .split_whitespace()
+            .filter_map(|part| part.parse().ok())
+            .collect();
+
+        let &[quantity, face] = numbers.as_slice() else {
+            println!("Bid with two numbers: how many dice, then which face.");
+            continue;
+        };
+
+        let raise = Bid {
+            quantity,
+            face: face as u32,
+        };
+
+        if !(1..=6).contains(&face) || !(1..=total).contains(&quantity) {
+            println!("Bid between 1 and {total} dice, with a face from 1 to 6.");
+        } else if bid.is_some_and(|bid| !raise.beats(&bid)) {
+            println!("Your bid must beat {}.", bid.expect("checked above"));
+        } else {
+            return Ok(Some(Move::Raise(raise)));
+        }
+    }
+}
+
+/// A computer player's move, knowing its own `dice` and how many dice are
+/// `hidden` from it.
+fn rival_move(
+    strategy: Strategy,
+    dice: &[u32],
+    hidden: usize,
+    bid: Option<Bid>,
+    rng: &mut impl Rng,
+) -> Move {
+    // The chance that a bid is true, given the dice this player can see.
+    let chance = |bid: Bid| {
+        let own = dice.iter().filter(|&&die| die == bid.face).count();
+        chance_of_at_least(bid.quantity.saturating_sub(own), hidden)
+    };
+
+    // The smallest raise on each face.
+    let raises: Vec<Bid> = (1..=6)
+        .map(|face| {
+            let quantity = match bid {
+                Some(bid) if face > bid.face => bid.quantity,
+                Some(bid) => bid.quantity + 1,
+                None => 1,
+            };
+            Bid { quantity, face }
+        })
+        .filter(|raise| raise.quantity <= dice.len() + hidden)
+        .collect();
+    let safest = raises
+        .iter()
+        .copied()
+        .max_by(|a, b| chance(*a).total_cmp(&chance(*b)));
+
+    let (Some(bid), Some(safest)) = (bid, safest) else {
+        return match (bid, safest) {
+            // There is no higher bid left to make.
+            (Some(_), None) => Move::Challenge,
+            (_, raise) => Move::Raise(raise.expect("an opening bid is always possible")),
+        };
+    };
+
+    match strategy {
+        Strategy::Cautious if chance(bid) < 0.5 => Move::Challenge,
+        Strategy::Cautious => Move::Raise(safest),
+        Strategy::Greedy if chance(bid) < 0.2 => Move::Challenge,
+        // Bluffs one die higher than it needs to, when it can.
+        Strategy::Greedy if safest.quantity < dice.len() + hidden => Move::Raise(Bid {
+            quantity: safest.quantity + 1,
+            ..safest
+        }),
+        Strategy::Greedy => Move::Raise(safest),
+        // Challenging wins if the bid is false, and raising wins about as
+        // often as the raise is true.
+        Strategy::Optimal if 1.0 - chance(bid) > chance(safest) => Move::Challenge,
+        Strategy::Optimal => Move::Raise(safest),
+        Strategy::Random if rng.random_bool(0.3) => Move::Challenge,
+        Strategy::Random => Move::Raise(*raises.choose(rng).expect("there is a raise")),
+    }
+}
+
+/// The chance that at least `needed` of `dice` unseen dice show a given
+/// face.
+fn chance_of_at_least(needed: usize, dice: usize) -> f64 {
+    let p: f64 = 1.0 / 6.0;
+
+    (needed..=dice)
+        .map(|k| {
+            let ways = (0..k).fold(1.0, |ways, i| ways * (dice - i) as f64 / (i + 1) as f64);
+            ways * p.powi(k as i32) * (1.0 - p).powi((dice - k) as i32)
+        })
+        .sum()
+}
+
+/// Shows dice as faces, e.g. `⚀ ⚃ ⚃`.
+fn faces(dice: &[u32]) -> String {
+    dice.iter()
+        .map(|&die| glyph(Die::D6, die))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,126 @@
+mod craps;
+mod highest;
+mod liars;
+mod pig;
+mod yahtzee;
+
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::{anyhow, Result};
+use rand::{rngs::ThreadRng, Rng};
+
+use crate::{config::Config, rival::Strategy};
+
+/// How a game ended for the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Won,
+    Lost,
+    Draw,
+    /// The player quit before the game was over.
+    Quit,
+}
+
+/// The rules of one dice game played against the Rival.
+pub trait Rules {
+    /// Explains how the game is played.
+    fn intro(&self) -> String;
+
+    /// Plays one game to the end.
+    fn play(&mut self, rng: &mut ThreadRng) -> Result<Outcome>;
+
+    /// Plays a game between two computer players without any output.
+    /// Returns the outcome for the first player, or `None` if the game has
+    /// no decisions to make.
+    fn simulate(&self, _players: [Strategy; 2], _rng: &mut ThreadRng) -> Option<Outcome> {
+        None
+    }
+}
+
+/// The games that can be picked from the menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Game {
+    Highest,
+    Pig,
+    Yahtzee,
+    Liars,
+    Craps,
+}
+
+impl Game {
+    /// Every game, in menu order.
+    pub const ALL: [Game; 5] = [
+        Game::Highest,
+        Game::Pig,
+        Game::Yahtzee,
+        Game::Liars,
+        Game::Craps,
+    ];
+
+    /// A one line summary for the menu.
+    pub fn summary(&self) -> &'static str {
+        match self {
+            Game::Highest => "the highest roll wins the round",
+            Game::Pig => "roll or hold, but a 1 loses your turn",
+            Game::Yahtzee => "fill a score card with re-rolls",
+            Game::Liars => "bid on hidden dice and call bluffs",
+            Game::Craps => "win on 7 or 11, then make your point",
+        }
+    }
+
+    /// Whether the Rival makes choices in this game, so its strategy
+    /// matters.
+    pub fn has_strategy(&self) -> bool {
+        matches!(self, Game::Pig | Game::Yahtzee | Game::Liars)
+    }
+
+    /// The rules of the game against the `rival`, using `config` where it
+    /// applies.
+    pub fn rules(&self, config: &Config, rival: Strategy) -> Box<dyn Rules> {
+        match self {
+            Game::Highest => Box::new(highest::Highest::new(*config)),
+            Game::Pig => Box::new(pig::Pig::new(rival)),
+            Game::Yahtzee => Box::new(yahtzee::Yahtzee::new(rival)),
+            Game::Liars => Box::new(liars::Liars::new(rival)),
+            Game::Craps => Box::new(craps::Craps::new(config.rounds)),
+        }
+    }
+}
+
+impl Display for Game {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Game::Highest => "Highest roll",
+                Game::Pig => "Pig",
+                Game::Yahtzee => "Yahtzee",
+                Game::Liars => "Liar's Dice",
+                Game::Craps => "Craps",
+            }
+        )
+    }
+}
+
+impl FromStr for Game {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "highest" => Ok(Game::Highest),
+            "pig" => Ok(Game::Pig),
+            "yahtzee" => Ok(Game::Yahtzee),
+            "liars" | "liar" => Ok(Game::Liars),
+            "craps" => Ok(Game::Craps),
+            _ => Err(anyhow!(
+                "Unknown game `{s}`. Use highest, pig, yahtzee, liars or craps."
+            )),
+        }
+    }
+}
+
+/// Rolls one six-sided die.
+fn d6(rng: &mut impl Rng) -> u32 {
+    rng.random_range(1..=6)
+}
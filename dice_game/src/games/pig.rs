@@ -0,0 +1,179 @@
+use anyhow::Result;
+use rand::{rngs::ThreadRng, Rng};
+
+use super::{d6, Outcome, Rules};
+use crate::{config::wrap, config::Die, input::choose, render::glyph, rival::Strategy};
+
+/// The score that wins the game.
+const TARGET: u32 = 100;
+/// Holding at this turn total banks the most points per turn on average:
+/// below it another roll is expected to gain more than it risks.
+const BEST_HOLD_AT: u32 = 20;
+
+/// Players take turns rolling a die as often as they like, adding each roll
+/// to their turn total. Holding banks the total, but rolling a 1 loses it.
+#[derive(Debug)]
+pub struct Pig {
+    rival: Strategy,
+}
+
+impl Pig {
+    pub fn new(rival: Strategy) -> Self {
+        Self { rival }
+    }
+}
+
+impl Rules for Pig {
+    fn intro(&self) -> String {
+        wrap(
+            &format!(
+                "Pig: on your turn roll a die as often as you like. Every roll is added \
+                 to your turn total, which you bank by holding. Roll a 1 and you lose the \
+                 whole turn total. The first player to bank {TARGET} points wins."
+            ),
+            60,
+        )
+    }
+
+    fn play(&mut self, rng: &mut ThreadRng) -> Result<Outcome> {
+        let (mut player, mut rival) = (0, 0);
+
+        loop {
+            println!();
+            println!("Your turn. You have {player}, the Rival has {rival}.");
+
+            let Some(points) = player_turn(player, rng)? else {
+                return Ok(Outcome::Quit);
+            };
+            player += points;
+
+            if player >= TARGET {
+                println!("You banked {player} points.");
+                return Ok(Outcome::Won);
+            }
+
+            println!();
+            println!("The Rival's turn. You have {player}, the Rival has {rival}.");
+            rival += rival_turn(self.rival, rival, player, rng);
+
+            if rival >= TARGET {
+                println!("The Rival banked {rival} points.");
+                return Ok(Outcome::Lost);
+            }
+        }
+    }
+
+    fn simulate(&self, players: [Strategy; 2], rng: &mut ThreadRng) -> Option<Outcome> {
+        let mut scores = [0, 0];
+
+        for turn in 0.. {
+            let (me, other) = (turn % 2, (turn + 1) % 2);
+            let rolls = auto_turn(players[me], scores[me], scores[other], rng);
+            scores[me] += banked(&rolls);
+
+            if scores[me] >= TARGET {
+                break;
+            }
+        }
+
+        Some(if scores[0] >= TARGET {
+            Outcome::Won
+        } else {
+            Outcome::Lost
+        })
+    }
+}
+
+/// Rolls until the player holds or rolls a 1. Returns the points banked, or
+/// `None` if the player quits.
+fn player_turn(score: u32, rng: &mut ThreadRng) -> Result<Option<u32>> {
+    let mut total = 0;
+
+    loop {
+        let roll = d6(rng);
+
+        if roll == 1 {
+            println!("You rolled {} and lose your turn total.", glyph(Die::D6, 1));
+            return Ok(Some(0));
+        }
+
+        total += roll;
+        println!("You rolled {}. Turn total: {total}.", glyph(Die::D6, roll));
+
+        if score + total >= TARGET {
+            return Ok(Some(total));
+        }
+
+        match choose("[r]oll again or [h]old? ", &['r', 'h'])? {
+            Some('h') => {
+                println!("You bank {total}.");
+                return Ok(Some(total));
+            }
+            Some(_) => {}
+            None => return Ok(None),
+        }
+    }
+}
+
+/// Plays the Rival's turn and shows its rolls. Returns the points banked.
+fn rival_turn(strategy: Strategy, score: u32, opponent: u32, rng: &mut ThreadRng) -> u32 {
+    let rolls = auto_turn(strategy, score, opponent, rng);
+    let mut total = 0;
+
+    for &roll in &rolls {
+        if roll == 1 {
+            println!(
+                "Rival rolled {} and loses its turn total.",
+                glyph(Die::D6, 1)
+            );
+            return 0;
+        }
+
+        total += roll;
+        println!(
+            "Rival rolled {}. Turn total: {total}.",
+            glyph(Die::D6, roll)
+        );
+    }
+
+    println!("The Rival banks {total}.");
+    total
+}
+
+/// Rolls for a computer player until its strategy holds or it rolls a 1.
+/// Returns every roll.
+fn auto_turn(strategy: Strategy, score: u32, opponent: u32, rng: &mut impl Rng) -> Vec<u32> {
+    let mut rolls = vec![];
+
+    loop {
+        let roll = d6(rng);
+        rolls.push(roll);
+
+        let total = banked(&rolls);
+        if roll == 1 || score + total >= TARGET || holds(strategy, total, opponent, rng) {
+            return rolls;
+        }
+    }
+}
+
+/// The points a turn with these rolls banks.
+fn banked(rolls: &[u32]) -> u32 {
+    if rolls.contains(&1) {
+        0
+    } else {
+        rolls.iter().sum()
+    }
+}
+
+/// Whether a computer player holds with `total` points in this turn.
+fn holds(strategy: Strategy, total: u32, opponent: u32, rng: &mut impl Rng) -> bool {
+    match strategy {
+        Strategy::Cautious => total >= 10,
+        Strategy::Greedy => total >= 35,
+        // Once the opponent is close to winning, banking a little at a
+        // time won't catch up.
+        Strategy::Optimal if opponent + BEST_HOLD_AT >= TARGET => total >= 2 * BEST_HOLD_AT,
+        Strategy::Optimal => total >= BEST_HOLD_AT,
+        Strategy::Random => rng.random_bool(0.3),
+    }
+}
@@ -0,0 +1,433 @@
+use anyhow::Result;
+use rand::{rngs::ThreadRng, Rng};
+
+use super::{d6, Outcome, Rules};
+use crate::{
+    config::{wrap, Die},
+    input::read_line,
+    render::glyph,
+    rival::Strategy,
+};
+
+/// How many dice are rolled each turn.
+const DICE: usize = 5;
+/// How many times the dice can be re-rolled each turn.
+const REROLLS: u32 = 2;
+/// The upper section total needed for the bonus.
+const BONUS_THRESHOLD: u32 = 63;
+const BONUS: u32 = 35;
+/// How many rolls the optimal Rival tries for each way of keeping dice.
+const SAMPLES: u32 = 8;
+
+/// A box on the score card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Category {
+    Ones,
+    Twos,
+    Threes,
+    Fours,
+    Fives,
+    Sixes,
+    ThreeOfAKind,
+    FourOfAKind,
+    FullHouse,
+    SmallStraight,
+    LargeStraight,
+    Yahtzee,
+    Chance,
+}
+
+impl Category {
+    const ALL: [Category; 13] = [
+        Category::Ones,
+        Category::Twos,
+        Category::Threes,
+        Category::Fours,
+        Category::Fives,
+        Category::Sixes,
+        Category::ThreeOfAKind,
+        Category::FourOfAKind,
+        Category::FullHouse,
+        Category::SmallStraight,
+        Category::LargeStraight,
+        Category::Yahtzee,
+        Category::Chance,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Category::Ones => "Ones",
+            Category::Twos => "Twos",
+            Category::Threes => "Threes",
+            Category::Fours => "Fours",
+            Category::Fives => "Fives",
+            Category::Sixes => "Sixes",
+            Category::ThreeOfAKind => "Three of a kind",
+            Category::FourOfAKind => "Four of a kind",
+            Category::FullHouse => "Full house",
+            Category::SmallStraight => "Small straight",
+            Category::LargeStraight => "Large straight",
+            Category::Yahtzee => "Yahtzee",
+            Category::Chance => "Chance",
+        }
+    }
+
+    /// Whether the category counts towards the upper section bonus.
+    fn is_upper(&self) -> bool {
+        (*self as usize) < 6
+    }
+
+    /// What `dice` would score in this category.
+    fn score(&self, dice: &[u32]) -> u32 {
+        let counts = counts(dice);
+        let sum = dice.iter().sum();
+        let longest_run = (1..=6)
+            .scan(0, |run, face| {
+                *run = if counts[face] > 0 { *run + 1 } else { 0 };
+                Some(*run)
+            })
+            .max()
+            .unwrap_or(0);
+
+        match self {
+            Category::Ones
+            | Category::Twos
+            | Category::Threes
+            | Category::Fours
+            | Category::Fives
+            | Category::Sixes => {
+                let face = *self as usize + 1;
+                counts[face] * face as u32
+            }
+            Category::ThreeOfAKind if counts.iter().any(|&count| count >= 3) => sum,
+            Category::FourOfAKind if counts.iter().any(|&count| count >= 4) => sum,
+            Category::FullHouse
+                if counts.contains(&3) && counts.contains(&2) || counts.contains(&5) =>
+            {
+                25
+            }
+            Category::SmallStraight if longest_run >= 4 => 30,
+            Category::LargeStraight if longest_run >= 5 => 40,
+            Category::Yahtzee if counts.contains(&5) => 50,
+            Category::Chance => sum,
+            _ => 0,
+        }
+    }
+}
+
+/// How many dice show each face, indexed by the face.
+fn counts(dice: &[u32]) -> [u32; 7] {
+    let mut counts = [0; 7];
+    for &die in dice {
+        counts[die as usize] += 1;
+    }
+    counts
+}
+
+/// One player's score card.
+#[derive(Debug, Default)]
+struct Card {
+    scores: [Option<u32>; 13],
+}
+
+impl Card {
+    fn open(&self) -> impl Iterator<Item = Category> + '_ {
+        Category::ALL
+            .into_iter()
+            .filter(|category| self.scores[*category as usize].is_none())
+    }
+
+    fn upper(&self) -> u32 {
+        Category::ALL
+            .iter()
+            .filter(|category| category.is_upper())
+            .filter_map(|category| self.scores[*category as usize])
+            .sum()
+    }
+
+    fn bonus(&self) -> u32 {
+        if self.upper() >= BONUS_THRESHOLD {
+            BONUS
+        } else {
+            0
+        }
+    }
+
+    fn total(&self) -> u32 {
+        self.scores.iter().flatten().sum::<u32>() + self.bonus()
+    }
+}
+
+/// Both players fill a score card of 13 boxes, one per turn, rolling five
+/// dice and re-rolling any of them up to twice.
+#[derive(Debug)]
+pub struct Yahtzee {
+    rival: Strategy,
+}
+
+impl Yahtzee {
+    pub fn new(rival: Strategy) -> Self {
+        Self { rival }
+    }
+}
+
+impl Rules for Yahtzee {
+    fn intro(&self) -> String {
+        wrap(
+            &format!(
+                "Yahtzee: each turn roll {DICE} dice, then re-roll any of them up to \
+                 {REROLLS} times. Score the result in one of the 13 boxes on your card. \
+                 An upper section of {BONUS_THRESHOLD} or more earns a {BONUS} point bonus. \
+                 The higher total after 13 turns wins."
+            ),
+            60,
+        )
+    }
+
+    fn play(&mut self, rng: &mut ThreadRng) -> Result<Outcome> {
+        let (mut player, mut rival) = (Card::default(), Card::default());
+
+        for turn in 1..=Category::ALL.len() {
+            println!();
+            println!("Turn {turn} of {}", Category::ALL.len());
+
+            if !player_turn(&mut player, rng)? {
+                return Ok(Outcome::Quit);
+            }
+
+            rival_turn(self.rival, &mut rival, rng);
+            println!(
+                "The score is now - You : {}. Rival : {}.",
+                player.total(),
+                rival.total()
+            );
+        }
+
+        println!();
+        print_cards(&player, &rival);
+
+        Ok(match player.total().cmp(&rival.total()) {
+            std::cmp::Ordering::Less => Outcome::Lost,
+            std::cmp::Ordering::Equal => Outcome::Draw,
+            std::cmp::Ordering::Greater => Outcome::Won,
+        })
+    }
+
+    fn simulate(&self, players: [Strategy; 2], rng: &mut ThreadRng) -> Option<Outcome> {
+        let mut cards = [Card::default(), Card::default()];
+
+        for _ in Category::ALL {
+            for (strategy, card) in players.into_iter().zip(&mut cards) {
+                let (dice, category) = auto_turn(strategy, card, rng);
+                card.scores[category as usize] = Some(category.score(&dice));
+            }
+        }
+
+        Some(match cards[0].total().cmp(&cards[1].total()) {
+            std::cmp::Ordering::Less => Outcome::Lost,
+            std::cmp::Ordering::Equal => Outcome::Draw,
+            std::cmp::Ordering::Greater => Outcome::Won,
+        })
+    }
+}
+
+/// Shows dice with the positions used to pick them, e.g. `1:⚂ 2:⚅`.
+fn show(dice: &[u32]) -> String {
+    dice.iter()
+        .enumerate()
+        .map(|(i, &die)| format!("{}:{}", i + 1, glyph(Die::D6, die)))
+        .collect::<Vec<_>>()
+        .join("  ")
+}
+
+/// Plays the player's turn. Returns `false` if the player quits.
+fn player_turn(card: &mut Card, rng: &mut ThreadRng) -> Result<bool> {
+    let mut dice: Vec<u32> = (0..DICE).map(|_| d6(rng)).collect();
+
+    for reroll in (1..=REROLLS).rev() {
+        println!("You rolled  {}", show(&dice));
+
+        let Some(picked) = read_positions(reroll)? else {
+            return Ok(false);
+        };
+
+        if picked.is_empty() {
+            break;
+        }
+
+        for position in picked {
+            dice[position] = d6(rng);
+        }
+    }
+
+    println!("Your dice:  {}", show(&dice));
+
+    let open: Vec<Category> = card.open().collect();
+    for (i, category) in open.iter().enumerate() {
+        println!(
+            "  {:>2}. {:<16} {:>2}",
+            i + 1,
+            category.name(),
+            category.score(&dice)
+        );
+    }
+
+    loop {
+        let Some(input) = read_line("Score in which box? ")? else {
+            return Ok(false);
+        };
+
+        match input.parse::<usize>() {
+            Ok(choice) if (1..=open.len()).contains(&choice) => {
+                let category = open[choice - 1];
+                card.scores[category as usize] = Some(category.score(&dice));
+                return Ok(true);
+            }
+            _ => println!("Please pick a box between 1 and {}.", open.len()),
+        }
+    }
+}
+
+/// Asks which dice to re-roll, e.g. `135`. Returns the positions, which are
+/// empty if the player keeps everything, or `None` if the input was closed.
+fn read_positions(rerolls_left: u32) -> Result<Option<Vec<usize>>> {
+    loop {
+        let Some(input) = read_line(&format!(
+            "Dice to re-roll ({rerolls_left} left, e.g. 135), or Enter to keep: "
+        ))?
+        else {
+            return Ok(None);
+        };
+
+        let positions: Option<Vec<usize>> = input
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| {
+                c.to_digit(10)
+                    .map(|digit| digit as usize)
+                    .filter(|digit| (1..=DICE).contains(digit))
+                    .map(|digit| digit - 1)
+            })
+            .collect();
+
+        match positions {
+            Some(mut positions) => {
+                positions.sort();
+                positions.dedup();
+                return Ok(Some(positions));
+            }
+            None => println!("Use the positions 1 to {DICE}."),
+        }
+    }
+}
+
+/// Plays the Rival's turn and shows what it scored.
+fn rival_turn(strategy: Strategy, card: &mut Card, rng: &mut ThreadRng) {
+    let (dice, category) = auto_turn(strategy, card, rng);
+    let score = category.score(&dice);
+    card.scores[category as usize] = Some(score);
+
+    let faces: Vec<String> = dice.iter().map(|&die| glyph(Die::D6, die)).collect();
+    println!(
+        "Rival rolled {} and scored {score} for {}.",
+        faces.join(" "),
+        category.name()
+    );
+}
+
+/// Rolls and re-rolls for a computer player. Returns its final dice and the
+/// box it scores them in.
+fn auto_turn(strategy: Strategy, card: &Card, rng: &mut impl Rng) -> (Vec<u32>, Category) {
+    let mut dice: Vec<u32> = (0..DICE).map(|_| d6(rng)).collect();
+
+    for _ in 0..REROLLS {
+        let keep = keep(strategy, &dice, card, rng);
+
+        if keep.iter().all(|&keep| keep) {
+            break;
+        }
+
+        for (die, _) in dice.iter_mut().zip(keep).filter(|(_, keep)| !keep) {
+            *die = d6(rng);
+        }
+    }
+
+    (dice.clone(), best_box(card, &dice))
+}
+
+/// The open box worth the most for `dice`. Ties go to the earliest box, so
+/// a zero is spent on the Ones rather than on a box that could be worth a
+/// lot later.
+fn best_box(card: &Card, dice: &[u32]) -> Category {
+    card.open()
+        .max_by_key(|category| (category.score(dice), std::cmp::Reverse(*category as usize)))
+        .expect("there is an open box every turn")
+}
+
+/// Which dice a computer player keeps before re-rolling the rest.
+fn keep(strategy: Strategy, dice: &[u32], card: &Card, rng: &mut impl Rng) -> Vec<bool> {
+    let counts = counts(dice);
+    let most = (1..=6)
+        .max_by_key(|&face| (counts[face], face))
+        .unwrap_or(6) as u32;
+
+    match strategy {
+        // Holds on to the most common face and anything high, for safe
+        // points in the upper section and Chance.
+        Strategy::Cautious => dice.iter().map(|&die| die == most || die >= 5).collect(),
+        // Always chases a Yahtzee.
+        Strategy::Greedy => dice.iter().map(|&die| die == most).collect(),
+        // Tries every way of keeping dice and picks the one with the best
+        // average score.
+        Strategy::Optimal => {
+            let mut sampled_total = |mask: u32| {
+                (0..SAMPLES)
+                    .map(|_| {
+                        let rolled: Vec<u32> = dice
+                            .iter()
+                            .enumerate()
+                            .map(|(i, &die)| if mask & (1 << i) != 0 { die } else { d6(rng) })
+                            .collect();
+                        best_box(card, &rolled).score(&rolled)
+                    })
+                    .sum::<u32>()
+            };
+            let all = (1 << DICE) - 1;
+            let mask = (0..=all)
+                .map(|mask| {
+                    // Keeping everything needs no samples.
+                    let total = if mask == all {
+                        best_box(card, dice).score(dice) * SAMPLES
+                    } else {
+                        sampled_total(mask)
+                    };
+                    (total, mask)
+                })
+                .max()
+                .map_or(all, |(_, mask)| mask);
+
+            (0..DICE).map(|i| mask & (1 << i) != 0).collect()
+        }
+        Strategy::Random => dice.iter().map(|_| rng.random_bool(0.5)).collect(),
+    }
+}
+
+/// Prints both score cards side by side.
+fn print_cards(player: &Card, rival: &Card) {
+    let cell = |score: Option<u32>| score.map_or(String::from("-"), |score| score.to_string());
+
+    println!("{:<16} {:>5} {:>5}", "", "You", "Rival");
+    for category in Category::ALL {
+        println!(
+            "{:<16} {:>5} {:>5}",
+            category.name(),
+            cell(player.scores[category as usize]),
+            cell(rival.scores[category as usize])
+        );
+
+        if category == Category::Sixes {
+            println!("{:<16} {:>5} {:>5}", "Bonus", player.bonus(), rival.bonus());
+        }
+    }
+    println!("{:<16} {:>5} {:>5}", "Total", player.total(), rival.total());
+}
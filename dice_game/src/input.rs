@@ -0,0 +1,149 @@
+use std::io::{BufRead, IsTerminal, Write};
+
+use anyhow::Result;
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    terminal,
+};
+
+/// What the player asked for at a prompt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Continue,
+    /// The player pressed `q`, Escape or Ctrl+C, or the input was closed.
+    Quit,
+}
+
+/// Turns raw mode off again when dropped, even if reading a key failed.
+struct RawMode;
+
+impl RawMode {
+    fn enable() -> Result<Self> {
+        terminal::enable_raw_mode()?;
+        Ok(Self)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Prints `prompt` and waits for a single key press. When the input is not a
+/// terminal, a whole line is read instead.
+pub fn wait_for_key(prompt: &str) -> Result<Key> {
+    print!("{prompt}");
+    std::io::stdout().flush()?;
+
+    let key = if std::io::stdin().is_terminal() {
+        read_key()?
+    } else {
+        read_piped_key()?
+    };
+
+    println!();
+    Ok(key)
+}
+
+/// Reads one key press in raw mode, so the player doesn't need to press
+/// Enter.
+fn read_key() -> Result<Key> {
+    Ok(match read_raw_key()? {
+        Some(_) => Key::Continue,
+        None => Key::Quit,
+    })
+}
+
+/// Reads one key press in raw mode. Returns `None` for the keys that quit:
+/// `q`, Escape and Ctrl+C.
+fn read_raw_key() -> Result<Option<KeyCode>> {
+    let _raw_mode = RawMode::enable()?;
+
+    loop {
+        let Event::Key(KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+            ..
+        }) = event::read()?
+        else {
+            continue;
+        };
+
+        return Ok(match code {
+            KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => None,
+            KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => None,
+            code => Some(code),
+        });
+    }
+}
+
+/// Reads a line from input that is not a terminal, e.g. a pipe.
+fn read_piped_key() -> Result<Key> {
+    let mut input = String::new();
+
+    if std::io::stdin().lock().read_line(&mut input)? == 0 {
+        return Ok(Key::Quit);
+    }
+
+    Ok(if input.trim().eq_ignore_ascii_case("q") {
+        Key::Quit
+    } else {
+        Key::Continue
+    })
+}
+
+/// Prints `prompt` and waits for one of `choices`, ignoring other keys.
+/// Returns `None` if the player quits.
+pub fn choose(prompt: &str, choices: &[char]) -> Result<Option<char>> {
+    print!("{prompt}");
+    std::io::stdout().flush()?;
+
+    let choice = if std::io::stdin().is_terminal() {
+        loop {
+            match read_raw_key()? {
+                Some(KeyCode::Char(c)) if choices.contains(&c.to_ascii_lowercase()) => {
+                    break Some(c.to_ascii_lowercase())
+                }
+                Some(_) => {}
+                None => break None,
+            }
+        }
+    } else {
+        loop {
+            let mut input = String::new();
+            if std::io::stdin().lock().read_line(&mut input)? == 0 {
+                break None;
+            }
+
+            let c = input.trim().chars().next().map(|c| c.to_ascii_lowercase());
+            match c {
+                Some(c) if choices.contains(&c) => break Some(c),
+                Some('q') => break None,
+                _ => {}
+            }
+        }
+    };
+
+    if let Some(c) = choice {
+        print!("{c}");
+    }
+    println!();
+    Ok(choice)
+}
+
+/// Prints `prompt` and reads a whole, trimmed line. Returns `None` if the
+/// input was closed.
+pub fn read_line(prompt: &str) -> Result<Option<String>> {
+    print!("{prompt}");
+    std::io::stdout().flush()?;
+
+    let mut input = String::new();
+    if std::io::stdin().lock().read_line(&mut input)? == 0 {
+        println!();
+        return Ok(None);
+    }
+
+    Ok(Some(input.trim().to_string()))
+}
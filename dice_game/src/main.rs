@@ -1,103 +1,119 @@
-use std::io::{Read, Write};
-
-use rand::{thread_rng, Rng};
-
-fn main() {
-    let mut player_points = 0;
-    let mut rival_points = 0;
+mod config;
+mod games;
+mod input;
+mod notation;
+mod render;
+mod rival;
+mod roll;
+mod simulate;
+
+use anyhow::{anyhow, Result};
+use config::Config;
+use games::{Game, Outcome};
+use input::{choose, wait_for_key, Key};
+use rival::{Moment, Strategy};
+
+fn main() -> Result<()> {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+
+    if args.first().is_some_and(|arg| arg == "simulate") {
+        return simulate::run(args.drain(1..));
+    }
+    if args.first().is_some_and(|arg| arg == "roll") {
+        return roll::run(args.drain(1..));
+    }
 
-    let mut rng = thread_rng();
+    // A game can be picked on the command line, e.g. `dice_game pig`.
+    let picked = match args.first().map(|arg| arg.parse::<Game>()) {
+        Some(Ok(game)) => {
+            args.remove(0);
+            Some(game)
+        }
+        _ => None,
+    };
+    let config = Config::from_args(args)?;
 
     println!("Dice Game");
     println!();
-    println!("In this game you and a computer Rival will play 10 rounds");
-    println!("where you will each roll a 6-sided dice, and the player");
-    println!("with the highest dice value will win the round. The player");
-    println!("who wins the most rounds wins the game. Good luck!");
-    println!();
 
-    print!("Press Enter key to start...");
-    std::io::stdout()
-        .flush()
-        .expect("Could not flush to standard output");
+    let game = match picked {
+        Some(game) => game,
+        None => match pick_game()? {
+            Some(game) => game,
+            None => return Ok(()),
+        },
+    };
 
-    {
-        let mut buffer = [0; 1];
-        std::io::stdin()
-            .read_exact(&mut buffer)
-            .expect("Failed to read input");
+    let mut rng = rand::rng();
+    // Only games where the Rival makes choices give it a personality.
+    let rival = if game.has_strategy() {
+        Some(config.rival.unwrap_or_else(|| Strategy::random(&mut rng)))
+    } else if config.rival.is_some() {
+        return Err(anyhow!(
+            "{game} has no choices to make, so `--rival` can't be used with it."
+        ));
+    } else {
+        None
     };
+    // The strategy is not used by games without choices.
+    let mut rules = game.rules(&config, rival.unwrap_or(Strategy::Optimal));
 
+    print!("{}", rules.intro());
     println!();
-    println!();
-
-    for i in 0..10 {
-        println!("Round {}", i + 1);
-
-        let rival_random_num = rng.gen_range(1..7);
-        println!("Rival rolled a {rival_random_num}");
-
-        print!("Press Enter key to roll the dice...");
-        std::io::stdout()
-            .flush()
-            .expect("Could not flush to standard output");
-
-        {
-            let mut buffer = [0; 1];
-            std::io::stdin()
-                .read_exact(&mut buffer)
-                .expect("Failed to read input");
-        };
-
+    if let Some(rival) = rival {
+        println!(
+            "Your Rival is {}: \"{}\"",
+            rival.name(),
+            rival.taunt(Moment::Start, &mut rng)
+        );
         println!();
+    }
 
-        let player_random_num = rng.gen_range(1..7);
-        println!("You rolled a {player_random_num}");
+    if wait_for_key("Press any key to start, or q to quit...")? == Key::Quit {
+        return Ok(());
+    }
+    println!();
 
-        match player_random_num.cmp(&rival_random_num) {
-            std::cmp::Ordering::Less => {
-                rival_points += 1;
-                println!("The Rival won this round.");
-            }
-            std::cmp::Ordering::Equal => println!("This round is a draw."),
-            std::cmp::Ordering::Greater => {
-                player_points += 1;
-                println!("You won this round.");
-            }
+    let rival_moment = match rules.play(&mut rng)? {
+        Outcome::Won => {
+            println!("You won!");
+            Some(Moment::Lost)
         }
+        Outcome::Lost => {
+            println!("You lost!");
+            Some(Moment::Won)
+        }
+        Outcome::Draw => {
+            println!("This game is a draw.");
+            None
+        }
+        Outcome::Quit => {
+            println!("Thanks for playing!");
+            None
+        }
+    };
 
-        println!("The score is now - You : {player_points}. Rival : {rival_points}.");
-
-        print!("Press Enter key to continue...");
-        std::io::stdout()
-            .flush()
-            .expect("Could not flush to standard output");
-
-        {
-            let mut buffer = [0; 1];
-            std::io::stdin()
-                .read_exact(&mut buffer)
-                .expect("Failed to read input");
-        };
-
-        println!();
-        println!();
+    if let (Some(rival), Some(moment)) = (rival, rival_moment) {
+        println!("{}: \"{}\"", rival.name(), rival.taunt(moment, &mut rng));
     }
 
-    println!("Game over.");
-    println!("The score is now - You : {player_points}. Rival : {rival_points}.");
+    Ok(())
+}
 
-    match player_points.cmp(&rival_points) {
-        std::cmp::Ordering::Less => println!("You lost!"),
-        std::cmp::Ordering::Equal => println!("You won!"),
-        std::cmp::Ordering::Greater => println!("This game is a draw."),
+/// Shows the menu of games. Returns `None` if the player quits.
+fn pick_game() -> Result<Option<Game>> {
+    for (i, game) in Game::ALL.iter().enumerate() {
+        println!("  {}. {game} - {}", i + 1, game.summary());
     }
+    println!();
 
-    println!("Press Enter key to exit...");
-    {
-        let mut buffer = [0; 1];
-        std::io::stdin()
-            .read_exact(&mut buffer)
-            .expect("Failed to read input");
-    };
+    let keys: Vec<char> = (1..=Game::ALL.len())
+        .filter_map(|i| char::from_digit(i as u32, 10))
+        .collect();
+    let choice = choose("Pick a game, or q to quit: ", &keys)?;
+    println!();
+
+    Ok(choice
+        .and_then(|key| key.to_digit(10))
+        .map(|digit| Game::ALL[digit as usize - 1]))
 }
@@ -0,0 +1,538 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use anyhow::{anyhow, Result};
+use rand::Rng;
+
+/// The most dice an expression can roll, over all of its terms.
+const MAX_DICE: u32 = 100;
+/// The most terms an expression can have.
+const MAX_TERMS: usize = 20;
+/// The most sides a die can have.
+const MAX_SIDES: u32 = 1_000;
+/// The largest number a modifier can add or take away.
+const MAX_MODIFIER: i64 = 1_000_000;
+/// How often a single exploding die can explode before it stops.
+const MAX_EXPLOSIONS: u32 = 100;
+/// Distributions are worked out exactly when that takes at most this many
+/// steps, and sampled otherwise.
+const MAX_EXACT_STEPS: u64 = 2_000_000;
+/// How many rolls a sampled distribution is based on at most.
+const SAMPLES: u32 = 100_000;
+/// How many dice a sampled distribution rolls at most, so expressions with
+/// many dice are rolled fewer times.
+const MAX_SAMPLED_DICE: u32 = 2_000_000;
+
+/// Which dice of a roll count towards its total.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Keep {
+    All,
+    Highest(u32),
+    Lowest(u32),
+}
+
+/// A group of identical dice, e.g. `4d6kh3` or `2d10!`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dice {
+    pub count: u32,
+    pub sides: u32,
+    /// Whether a die that rolls its highest face is rolled again and added.
+    pub exploding: bool,
+    pub keep: Keep,
+}
+
+/// One part of an expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Term {
+    Dice(Dice),
+    Modifier(i64),
+}
+
+/// A dice expression such as `3d6+2` or `4d6kh3 + 1d4 - 1`, as a list of
+/// terms with their signs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expression {
+    terms: Vec<(i64, Term)>,
+}
+
+/// The result of rolling one die, including any explosions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Die {
+    pub rolls: Vec<u32>,
+    /// Whether the die counts towards the total.
+    pub kept: bool,
+}
+
+impl Die {
+    pub fn value(&self) -> u32 {
+        self.rolls.iter().sum()
+    }
+}
+
+impl Display for Die {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rolls: Vec<String> = self.rolls.iter().map(u32::to_string).collect();
+        let rolls = rolls.join("!+");
+
+        if self.kept {
+            write!(f, "{rolls}")
+        } else {
+            write!(f, "({rolls})")
+        }
+    }
+}
+
+/// The result of rolling one term.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TermRoll {
+    pub sign: i64,
+    pub term: Term,
+    /// Each die rolled, empty for a modifier.
+    pub dice: Vec<Die>,
+    pub total: i64,
+}
+
+impl Dice {
+    /// Rolls every die, then marks which ones are kept.
+    fn roll(&self, rng: &mut impl Rng) -> Vec<Die> {
+        let mut dice: Vec<Die> = (0..self.count)
+            .map(|_| {
+                let mut rolls = vec![rng.random_range(1..=self.sides)];
+
+                while self.exploding
+                    && rolls.last() == Some(&self.sides)
+                    && rolls.len() <= MAX_EXPLOSIONS as usize
+                {
+                    rolls.push(rng.random_range(1..=self.sides));
+                }
+
+                Die { rolls, kept: true }
+            })
+            .collect();
+
+        let dropped = match self.keep {
+            Keep::All => vec![],
+            Keep::Highest(keep) => sorted_positions(&dice, false, self.count - keep),
+            Keep::Lowest(keep) => sorted_positions(&dice, true, self.count - keep),
+        };
+
+        for i in dropped {
+            dice[i].kept = false;
+        }
+
+        dice
+    }
+
+    /// The chance of every total, if it can be worked out exactly.
+    fn distribution(&self) -> Option<BTreeMap<i64, f64>> {
+        if self.exploding {
+            return None;
+        }
+
+        let one_die: BTreeMap<i64, f64> = (1..=self.sides as i64)
+            .map(|face| (face, 1.0 / self.sides as f64))
+            .collect();
+
+        if self.keep == Keep::All {
+            // Each die is convolved with every total the dice before it can
+            // make.
+            let count = u64::from(self.count);
+            let sides = u64::from(self.sides);
+            let steps = count * (count * (sides - 1) + 2) / 2 * sides;
+            if steps > MAX_EXACT_STEPS {
+                return None;
+            }
+
+            return Some(
+                (0..self.count).fold(BTreeMap::from([(0, 1.0)]), |total, _| {
+                    convolve(&total, &one_die)
+                }),
+            );
+        }
+
+        // Dropping dice depends on the whole roll, so every roll is counted.
+        let outcomes = (self.sides as u64).checked_pow(self.count)?;
+        if outcomes.saturating_mul(u64::from(self.count)) > MAX_EXACT_STEPS {
+            return None;
+        }
+
+        let mut distribution = BTreeMap::new();
+        let mut faces = vec![1; self.count as usize];
+
+        for _ in 0..outcomes {
+            let mut sorted = faces.clone();
+            sorted.sort_unstable();
+            let kept = match self.keep {
+                Keep::All => &sorted[..],
+                Keep::Highest(keep) => &sorted[(self.count - keep) as usize..],
+                Keep::Lowest(keep) => &sorted[..keep as usize],
+            };
+
+            *distribution.entry(kept.iter().sum::<i64>()).or_insert(0.0) += 1.0 / outcomes as f64;
+
+            // Counts through every roll like an odometer.
+            for face in faces.iter_mut() {
+                if *face < self.sides as i64 {
+                    *face += 1;
+                    break;
+                }
+                *face = 1;
+            }
+        }
+
+        Some(distribution)
+    }
+}
+
+/// The positions of the `n` lowest dice, or the `n` highest if `highest`.
+fn sorted_positions(dice: &[Die], highest: bool, n: u32) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..dice.len()).collect();
+    order.sort_by_key(|&i| dice[i].value());
+    if highest {
+        order.reverse();
+    }
+    order.truncate(n as usize);
+    order
+}
+
+/// The distribution of the sum of two independent totals.
+fn convolve(a: &BTreeMap<i64, f64>, b: &BTreeMap<i64, f64>) -> BTreeMap<i64, f64> {
+    let mut sum = BTreeMap::new();
+
+    for (x, p) in a {
+        for (y, q) in b {
+            *sum.entry(x + y).or_insert(0.0) += p * q;
+        }
+    }
+
+    sum
+}
+
+impl Expression {
+    /// How many dice the expression rolls, not counting explosions.
+    fn dice(&self) -> u32 {
+        self.terms
+            .iter()
+            .map(|(_, term)| match term {
+                Term::Dice(dice) => dice.count,
+                Term::Modifier(_) => 0,
+            })
+            .sum()
+    }
+
+    /// Rolls every term.
+    pub fn roll(&self, rng: &mut impl Rng) -> Vec<TermRoll> {
+        self.terms
+            .iter()
+            .map(|&(sign, term)| match term {
+                Term::Dice(dice) => {
+                    let rolled = dice.roll(rng);
+                    let total = rolled
+                        .iter()
+                        .filter(|die| die.kept)
+                        .map(|die| die.value() as i64)
+                        .sum::<i64>();
+
+                    TermRoll {
+                        sign,
+                        term,
+                        dice: rolled,
+                        total: sign * total,
+                    }
+                }
+                Term::Modifier(value) => TermRoll {
+                    sign,
+                    term,
+                    dice: vec![],
+                    total: sign * value,
+                },
+            })
+            .collect()
+    }
+
+    /// The chance of every total, and whether it was worked out exactly
+    /// rather than estimated from many rolls.
+    pub fn distribution(&self, rng: &mut impl Rng) -> (BTreeMap<i64, f64>, bool) {
+        let exact =
+            self.terms
+                .iter()
+                .try_fold(BTreeMap::from([(0, 1.0)]), |total, &(sign, term)| {
+                    let term = match term {
+                        Term::Dice(dice) => dice.distribution()?,
+                        Term::Modifier(value) => BTreeMap::from([(value, 1.0)]),
+                    };
+                    if (total.len() * term.len()) as u64 > MAX_EXACT_STEPS {
+                        return None;
+                    }
+                    let signed = term.into_iter().map(|(x, p)| (sign * x, p)).collect();
+
+                    Some(convolve(&total, &signed))
+                });
+
+        if let Some(exact) = exact {
+            return (exact, true);
+        }
+
+        let samples = (MAX_SAMPLED_DICE / self.dice().max(1)).min(SAMPLES);
+        let mut sampled = BTreeMap::new();
+        for _ in 0..samples {
+            let total = self.roll(rng).iter().map(|term| term.total).sum::<i64>();
+            *sampled.entry(total).or_insert(0.0) += 1.0 / samples as f64;
+        }
+
+        (sampled, false)
+    }
+}
+
+impl Display for Term {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Term::Modifier(value) => write!(f, "{value}"),
+            Term::Dice(dice) => {
+                write!(f, "{}d{}", dice.count, dice.sides)?;
+                if dice.exploding {
+                    write!(f, "!")?;
+                }
+                match dice.keep {
+                    Keep::All => Ok(()),
+                    Keep::Highest(keep) => write!(f, "kh{keep}"),
+                    Keep::Lowest(keep) => write!(f, "kl{keep}"),
+                }
+            }
+        }
+    }
+}
+
+impl Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (sign, term)) in self.terms.iter().enumerate() {
+            match (i, sign) {
+                (0, 1) => {}
+                (0, _) => write!(f, "-")?,
+                (_, 1) => write!(f, " + ")?,
+                _ => write!(f, " - ")?,
+            }
+            write!(f, "{term}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Expression {
+    type Err = anyhow::Error;
+
+    /// Parses terms like `3d6`, `d20`, `d%`, `4d6kh3`, `2d10kl1`, `3d6!`
+    /// and `5`, joined by `+` and `-`.
+    fn from_str(s: &str) -> Result<Self> {
+        let text: String = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase();
+
+        if text.is_empty() {
+            return Err(anyhow!(
+                "The dice expression is empty. Try something like 3d6+2."
+            ));
+        }
+
+        let mut terms = vec![];
+        let mut rest = text.as_str();
+        let mut sign = 1;
+
+        loop {
+            let end = rest.find(['+', '-']).unwrap_or(rest.len());
+            let (term, after) = rest.split_at(end);
+
+            if term.is_empty() {
+                // Only the first term may have a sign of its own.
+                if terms.is_empty() && after.starts_with('-') && sign == 1 {
+                    sign = -1;
+                    rest = &after[1..];
+                    continue;
+                }
+                return Err(anyhow!("Missing a term in `{s}`."));
+            }
+
+            terms.push((sign, parse_term(term)?));
+
+            let Some(operator) = after.chars().next() else {
+                break;
+            };
+            sign = if operator == '-' { -1 } else { 1 };
+            rest = &after[1..];
+        }
+
+        let expression = Self { terms };
+
+        if expression.terms.len() > MAX_TERMS {
+            return Err(anyhow!(
+                "Use at most {MAX_TERMS} terms, not {}.",
+                expression.terms.len()
+            ));
+        }
+        if expression.dice() > MAX_DICE {
+            return Err(anyhow!(
+                "Roll at most {MAX_DICE} dice in total, not {}.",
+                expression.dice()
+            ));
+        }
+
+        Ok(expression)
+    }
+}
+
+/// Parses a single term without its sign.
+fn parse_term(term: &str) -> Result<Term> {
+    let invalid = || anyhow!("`{term}` is not valid dice notation. Try something like 4d6kh3.");
+
+    let Some((count, rest)) = term.split_once('d') else {
+        let value: i64 = term.parse().map_err(|_| invalid())?;
+        if value > MAX_MODIFIER {
+            return Err(anyhow!("Modifiers go up to {MAX_MODIFIER}, not {value}."));
+        }
+        return Ok(Term::Modifier(value));
+    };
+
+    let count = if count.is_empty() {
+        1
+    } else {
+        count.parse().map_err(|_| invalid())?
+    };
+
+    // The sides run until the first letter or `!` after them.
+    let sides_end = rest
+        .find(|c: char| !c.is_ascii_digit() && c != '%')
+        .unwrap_or(rest.len());
+    let (sides, mut rest) = rest.split_at(sides_end);
+    let sides = match sides {
+        "%" => 100,
+        _ => sides.parse().map_err(|_| invalid())?,
+    };
+
+    let exploding = rest.starts_with('!');
+    if exploding {
+        rest = &rest[1..];
+    }
+
+    // A bare `k` keeps the highest dice.
+    let keep = if let Some(keep) = rest.strip_prefix("kl") {
+        Keep::Lowest(keep.parse().map_err(|_| invalid())?)
+    } else if let Some(keep) = rest.strip_prefix("kh").or_else(|| rest.strip_prefix('k')) {
+        Keep::Highest(keep.parse().map_err(|_| invalid())?)
+    } else if rest.is_empty() {
+        Keep::All
+    } else {
+        return Err(invalid());
+    };
+
+    if !(1..=MAX_DICE).contains(&count) {
+        return Err(anyhow!("Roll between 1 and {MAX_DICE} dice, not {count}."));
+    }
+    if !(1..=MAX_SIDES).contains(&sides) {
+        return Err(anyhow!(
+            "Dice have between 1 and {MAX_SIDES} sides, not {sides}."
+        ));
+    }
+    if exploding && sides == 1 {
+        return Err(anyhow!("A one-sided die would explode forever."));
+    }
+    if let Keep::Highest(keep) | Keep::Lowest(keep) = keep {
+        if !(1..=count).contains(&keep) {
+            return Err(anyhow!(
+                "Keep between 1 and {count} of the dice, not {keep}."
+            ));
+        }
+    }
+
+    Ok(Term::Dice(Dice {
+        count,
+        sides,
+        exploding,
+        keep,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exact(text: &str) -> BTreeMap<i64, f64> {
+        let (distribution, exact) = text
+            .parse::<Expression>()
+            .unwrap()
+            .distribution(&mut rand::rng());
+        assert!(exact, "{text} should be worked out exactly");
+        distribution
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn expressions_round_trip_through_text() {
+        for (text, shown) in [
+            ("3d6+2", "3d6 + 2"),
+            ("d%", "1d100"),
+            ("4d6kh3", "4d6kh3"),
+            ("4d6k3", "4d6kh3"),
+            ("2d10kl1", "2d10kl1"),
+            ("3d6!", "3d6!"),
+            ("-d6", "-1d6"),
+            ("2d20kl1 + 1d4 - 1", "2d20kl1 + 1d4 - 1"),
+        ] {
+            let expression: Expression = text.parse().unwrap();
+            assert_eq!(expression.to_string(), shown);
+            assert_eq!(shown.parse::<Expression>().unwrap(), expression);
+        }
+    }
+
+    #[test]
+    fn invalid_expressions_are_rejected() {
+        let too_many_terms = vec!["1"; MAX_TERMS + 1].join("+");
+
+        for text in [
+            "",
+            "d6--2",
+            "3d6+",
+            "4d6kh5",
+            "4d6kl0",
+            "1d1!",
+            "0d6",
+            "1d0",
+            "101d6",
+            "60d6+60d6",
+            "1000001",
+            "9223372036854775807+1",
+            "3x6",
+            too_many_terms.as_str(),
+        ] {
+            assert!(text.parse::<Expression>().is_err(), "{text}");
+        }
+    }
+
+    #[test]
+    fn exact_distributions_add_up_to_one() {
+        for text in ["1d20", "3d6+2", "4d6kh3", "2d20kl1 - 1d4", "10d100"] {
+            assert_close(exact(text).values().sum(), 1.0);
+        }
+    }
+
+    #[test]
+    fn exact_distributions_match_known_chances() {
+        let two_d6 = exact("2d6");
+        assert_close(two_d6[&2], 1.0 / 36.0);
+        assert_close(two_d6[&7], 6.0 / 36.0);
+        assert_close(two_d6[&12], 1.0 / 36.0);
+
+        let ability_score = exact("4d6kh3");
+        assert_close(ability_score[&3], 1.0 / 1296.0);
+        assert_close(ability_score[&18], 21.0 / 1296.0);
+        let mean: f64 = ability_score
+            .iter()
+            .map(|(&total, p)| total as f64 * p)
+            .sum();
+        assert_close(mean, 15_869.0 / 1296.0);
+    }
+}
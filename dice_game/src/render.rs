@@ -0,0 +1,195 @@
+use std::{
+    cmp::Ordering,
+    io::{IsTerminal, Write},
+    thread,
+    time::Duration,
+};
+
+use anyhow::Result;
+use crossterm::{
+    cursor::MoveUp,
+    queue,
+    style::{Color, Stylize},
+    terminal::{Clear, ClearType},
+};
+use rand::Rng;
+
+use crate::config::{Config, Die};
+
+/// How many frames the roll animation shows before the dice settle.
+const ROLL_FRAMES: u32 = 10;
+/// How long each frame of the roll animation is shown.
+const FRAME_TIME: Duration = Duration::from_millis(70);
+/// The most dice per player that are drawn as boxes. Bigger rolls are drawn
+/// on one line so they still fit in the terminal.
+const MAX_BOXED_DICE: usize = 4;
+/// The space between the player's and the Rival's dice.
+const GAP: &str = "     ";
+
+/// The width of a box drawn around a die, including its border.
+const BOX_WIDTH: usize = 9;
+
+/// The Unicode face for `value`, or the value itself for dice other than a
+/// d6.
+pub fn glyph(die: Die, value: u32) -> String {
+    match (die, value) {
+        (Die::D6, 1..=6) => char::from_u32(0x2680 + value - 1)
+            .expect("⚀ to ⚅ are valid characters")
+            .to_string(),
+        _ => value.to_string(),
+    }
+}
+
+/// Describes a roll with dice faces, e.g. `⚃ (4)` or `⚂ ⚄ ⚀ = 9`.
+pub fn describe(die: Die, dice: &[u32]) -> String {
+    let faces: Vec<String> = dice.iter().map(|&value| glyph(die, value)).collect();
+
+    match (die, dice) {
+        (Die::D6, [value]) => format!("{} ({value})", faces[0]),
+        (_, [value]) => format!("a {value}"),
+        _ => format!("{} = {}", faces.join(" "), dice.iter().sum::<u32>()),
+    }
+}
+
+/// Draws one die as box art, with pips for a d6 and the number otherwise.
+fn die_box(die: Die, value: u32) -> [String; 5] {
+    let pips: [[bool; 3]; 3] = match (die, value) {
+        (Die::D6, 1) => [[false; 3], [false, true, false], [false; 3]],
+        (Die::D6, 2) => [[true, false, false], [false; 3], [false, false, true]],
+        (Die::D6, 3) => [
+            [true, false, false],
+            [false, true, false],
+            [false, false, true],
+        ],
+        (Die::D6, 4) => [[true, false, true], [false; 3], [true, false, true]],
+        (Die::D6, 5) => [
+            [true, false, true],
+            [false, true, false],
+            [true, false, true],
+        ],
+        (Die::D6, 6) => [[true, false, true]; 3],
+        _ => {
+            return [
+                "┌───────┐".to_string(),
+                "│       │".to_string(),
+                format!("│{value:^7}│"),
+                "│       │".to_string(),
+                "└───────┘".to_string(),
+            ]
+        }
+    };
+
+    let row = |row: [bool; 3]| {
+        let cells: Vec<&str> = row.iter().map(|&pip| if pip { "●" } else { " " }).collect();
+        format!("│ {} │", cells.join(" "))
+    };
+
+    [
+        "┌───────┐".to_string(),
+        row(pips[0]),
+        row(pips[1]),
+        row(pips[2]),
+        "└───────┘".to_string(),
+    ]
+}
+
+/// Draws the dice of one player, colored by how the round went for them.
+fn side(die: Die, name: &str, dice: &[u32], result: Option<Ordering>) -> Vec<String> {
+    let color = match result {
+        Some(Ordering::Greater) => Some(Color::Green),
+        Some(Ordering::Less) => Some(Color::DarkGrey),
+        _ => None,
+    }
+    .filter(|_| std::io::stdout().is_terminal());
+    let paint = |text: String| match color {
+        Some(color) => text.with(color).to_string(),
+        None => text,
+    };
+    let total = dice.iter().sum::<u32>();
+    let title = match result {
+        Some(Ordering::Greater) => format!("{name}: {total} - wins the round!"),
+        _ => format!("{name}: {total}"),
+    };
+
+    if dice.len() > MAX_BOXED_DICE {
+        let faces: Vec<String> = dice.iter().map(|&value| glyph(die, value)).collect();
+        let faces = faces.join(" ");
+        let width = faces.chars().count().max(title.chars().count());
+
+        return vec![
+            paint(format!("{title:<width$}")),
+            paint(format!("{faces:<width$}")),
+        ];
+    }
+
+    let width = (BOX_WIDTH * dice.len()).max(title.chars().count());
+    let boxes: Vec<[String; 5]> = dice.iter().map(|&value| die_box(die, value)).collect();
+    let mut lines = vec![paint(format!("{title:<width$}"))];
+
+    for row in 0..5 {
+        let line: String = boxes.iter().map(|lines| lines[row].as_str()).collect();
+        lines.push(paint(format!("{line:<width$}")));
+    }
+
+    lines
+}
+
+/// Prints the player's and the Rival's dice side by side. The round winner
+/// is highlighted once `result`, the player's result, is known.
+fn board(die: Die, player: &[u32], rival: &[u32], result: Option<Ordering>) -> Vec<String> {
+    let left = side(die, "You", player, result);
+    let right = side(die, "Rival", rival, result.map(Ordering::reverse));
+
+    left.into_iter()
+        .zip(right)
+        .map(|(left, right)| format!("{left}{GAP}{right}"))
+        .collect()
+}
+
+/// Shows the player's dice tumbling next to the Rival's roll, then settles
+/// on `player` and highlights the winner. The animation is skipped when the
+/// output is not a terminal.
+pub fn roll_animation(
+    config: &Config,
+    player: &[u32],
+    rival: &[u32],
+    rng: &mut impl Rng,
+) -> Result<()> {
+    let mut stdout = std::io::stdout();
+    let result = player.iter().sum::<u32>().cmp(&rival.iter().sum());
+    let mut drawn = 0;
+
+    if stdout.is_terminal() {
+        for _ in 0..ROLL_FRAMES {
+            let tumbling: Vec<u32> = player
+                .iter()
+                .map(|_| rng.random_range(1..=config.die.sides()))
+                .collect();
+
+            drawn = redraw(&board(config.die, &tumbling, rival, None), drawn)?;
+            thread::sleep(FRAME_TIME);
+        }
+    }
+
+    redraw(&board(config.die, player, rival, Some(result)), drawn)?;
+    stdout.flush()?;
+
+    Ok(())
+}
+
+/// Prints `lines` over the `previous` lines that were drawn and returns how
+/// many lines are on screen now.
+fn redraw(lines: &[String], previous: u16) -> Result<u16> {
+    let mut stdout = std::io::stdout();
+
+    if previous > 0 {
+        queue!(stdout, MoveUp(previous), Clear(ClearType::FromCursorDown))?;
+    }
+
+    for line in lines {
+        writeln!(stdout, "{line}")?;
+    }
+    stdout.flush()?;
+
+    Ok(lines.len() as u16)
+}
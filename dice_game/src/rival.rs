@@ -0,0 +1,116 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::{anyhow, Result};
+use rand::{seq::IndexedRandom, Rng};
+
+/// How the Rival makes its decisions, and the personality that comes with
+/// it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Takes small, safe gains.
+    Cautious,
+    /// Pushes its luck for big scores.
+    Greedy,
+    /// Picks whatever wins the most on average.
+    Optimal,
+    /// Decides on a whim.
+    Random,
+}
+
+/// A moment in the game the Rival has something to say about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Moment {
+    Start,
+    Won,
+    Lost,
+}
+
+impl Strategy {
+    pub const ALL: [Strategy; 4] = [
+        Strategy::Cautious,
+        Strategy::Greedy,
+        Strategy::Optimal,
+        Strategy::Random,
+    ];
+
+    /// Picks a Rival at random.
+    pub fn random(rng: &mut impl Rng) -> Self {
+        *Self::ALL.choose(rng).expect("there are strategies")
+    }
+
+    /// The Rival's name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Strategy::Cautious => "Careful Cora",
+            Strategy::Greedy => "Greedy Gus",
+            Strategy::Optimal => "Professor Odds",
+            Strategy::Random => "Wild Willow",
+        }
+    }
+
+    /// Something the Rival says at `moment`.
+    pub fn taunt(&self, moment: Moment, rng: &mut impl Rng) -> &'static str {
+        let lines: &[&str] = match (self, moment) {
+            (Strategy::Cautious, Moment::Start) => &[
+                "Slow and steady, that's my motto.",
+                "Let's keep this nice and safe.",
+            ],
+            (Strategy::Cautious, Moment::Won) => &["Patience pays off.", "No need to rush, see?"],
+            (Strategy::Cautious, Moment::Lost) => &["Maybe I was a little too careful."],
+            (Strategy::Greedy, Moment::Start) => &[
+                "Fortune favours the bold!",
+                "I hope you brought a big wallet.",
+            ],
+            (Strategy::Greedy, Moment::Won) => &["Go big or go home!", "All of it, mine!"],
+            (Strategy::Greedy, Moment::Lost) => &["One more roll would have done it..."],
+            (Strategy::Optimal, Moment::Start) => &[
+                "I have done the maths. You should worry.",
+                "Every move I make is the expected best.",
+            ],
+            (Strategy::Optimal, Moment::Won) => &["As the numbers predicted."],
+            (Strategy::Optimal, Moment::Lost) => &[
+                "A statistically unlikely result.",
+                "Variance. Pure variance.",
+            ],
+            (Strategy::Random, Moment::Start) => &[
+                "I have no plan, and that's the plan!",
+                "Heads I roll, tails I roll anyway.",
+            ],
+            (Strategy::Random, Moment::Won) => &["Wheee! Did I win? I won!"],
+            (Strategy::Random, Moment::Lost) => &["Oh well, it was fun!"],
+        };
+
+        lines.choose(rng).expect("every moment has a line")
+    }
+}
+
+impl Display for Strategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Strategy::Cautious => "cautious",
+                Strategy::Greedy => "greedy",
+                Strategy::Optimal => "optimal",
+                Strategy::Random => "random",
+            }
+        )
+    }
+}
+
+impl FromStr for Strategy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "cautious" => Ok(Strategy::Cautious),
+            "greedy" => Ok(Strategy::Greedy),
+            "optimal" | "ev" => Ok(Strategy::Optimal),
+            "random" => Ok(Strategy::Random),
+            _ => Err(anyhow!(
+                "Unknown rival `{s}`. Use cautious, greedy, optimal or random."
+            )),
+        }
+    }
+}
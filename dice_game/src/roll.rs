@@ -0,0 +1,135 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+
+use crate::notation::{Expression, Term, TermRoll};
+
+/// How wide the bars of the distribution are at most.
+const BAR_WIDTH: usize = 40;
+/// The most rows the distribution shows before grouping totals together.
+const MAX_ROWS: i64 = 40;
+/// Totals less likely than this are too rare to show in the distribution.
+const MIN_SHOWN: f64 = 0.00005;
+
+/// Rolls a dice expression like `3d6+2` and shows every die. Takes the
+/// expression and an optional `--distribution` to also show the chance of
+/// every total.
+pub fn run(args: impl IntoIterator<Item = String>) -> Result<()> {
+    let mut words = vec![];
+    let mut distribution = false;
+
+    for arg in args {
+        match arg.as_str() {
+            "--distribution" | "-D" => distribution = true,
+            _ => words.push(arg),
+        }
+    }
+
+    if words.is_empty() {
+        return Err(anyhow!(
+            "Give some dice to roll, e.g. `dice_game roll 3d6+2` or `4d6kh3`."
+        ));
+    }
+
+    let expression: Expression = words.join(" ").parse()?;
+    let mut rng = rand::rng();
+    let rolled = expression.roll(&mut rng);
+
+    println!("Rolling {expression}");
+    for (i, term) in rolled.iter().enumerate() {
+        println!("  {}", breakdown(term, i == 0));
+    }
+    println!(
+        "Total: {}",
+        rolled.iter().map(|term| term.total).sum::<i64>()
+    );
+
+    if distribution {
+        let (chances, exact) = expression.distribution(&mut rng);
+        println!();
+        print_distribution(&chances, exact);
+    }
+
+    Ok(())
+}
+
+/// Shows one rolled term, e.g. `+ 4d6kh3: 6, 5, 3, (1) = 14`. Dropped dice
+/// are in brackets and exploded dice are joined with `!+`.
+fn breakdown(term: &TermRoll, first: bool) -> String {
+    let sign = match (first, term.sign) {
+        (true, 1) => "",
+        (true, _) => "-",
+        (false, 1) => "+ ",
+        (false, _) => "- ",
+    };
+
+    match term.term {
+        Term::Modifier(value) => format!("{sign}{value}"),
+        Term::Dice(_) => {
+            let dice: Vec<String> = term.dice.iter().map(ToString::to_string).collect();
+            format!(
+                "{sign}{}: {} = {}",
+                term.term,
+                dice.join(", "),
+                term.total.abs()
+            )
+        }
+    }
+}
+
+/// Prints the chance of every total, and of rolling at least that much.
+/// Wide ranges are grouped so the table stays short, and totals too
+/// unlikely to show up in it are left out.
+fn print_distribution(chances: &BTreeMap<i64, f64>, exact: bool) {
+    let (Some(&lowest), Some(&highest)) = (chances.keys().next(), chances.keys().next_back())
+    else {
+        return;
+    };
+    let mean: f64 = chances.iter().map(|(&total, p)| total as f64 * p).sum();
+    // Rounding can leave a mean of zero slightly negative.
+    let mean = if mean.abs() < 0.005 { 0.0 } else { mean };
+
+    let likely: Vec<i64> = chances
+        .iter()
+        .filter(|&(_, &p)| p >= MIN_SHOWN)
+        .map(|(&total, _)| total)
+        .collect();
+    let first = likely.first().copied().unwrap_or(lowest);
+    let last = likely.last().copied().unwrap_or(highest);
+    let group = ((last - first) / MAX_ROWS) + 1;
+    let chance = |totals| chances.range(totals).fold(0.0, |sum, (_, p)| sum + p);
+
+    let rows: Vec<(i64, i64, f64)> = (first..=last)
+        .step_by(group as usize)
+        .map(|start| {
+            let end = (start + group - 1).min(last);
+            (start, end, chance(start..=end))
+        })
+        .collect();
+    let most_likely = rows.iter().map(|&(_, _, p)| p).fold(0.0, f64::max);
+
+    if exact {
+        println!("Chance of each total:");
+    } else {
+        println!("Chance of each total, estimated from many rolls:");
+    }
+
+    let label_width = format!("{first}-{last}").len();
+
+    for (start, end, p) in rows {
+        let label = if start == end {
+            start.to_string()
+        } else {
+            format!("{start}-{end}")
+        };
+        let bar = "█".repeat((p / most_likely * BAR_WIDTH as f64).round() as usize);
+
+        println!(
+            "  {label:>label_width$}  {:>6.2}%  {:>6.2}% or more  {bar}",
+            p * 100.0,
+            chance(start..=highest) * 100.0
+        );
+    }
+
+    println!("Average: {mean:.2}, from {lowest} to {highest}.");
+}
@@ -0,0 +1,101 @@
+use anyhow::{anyhow, Result};
+
+use crate::{
+    config::Config,
+    games::{Game, Outcome},
+    rival::Strategy,
+};
+
+/// How many games each pair of strategies plays by default.
+const DEFAULT_GAMES: u32 = 500;
+
+/// Pits every strategy against every other one for a number of games and
+/// prints how often each of them won. Takes the game to simulate and an
+/// optional `--games N`.
+pub fn run(args: impl IntoIterator<Item = String>) -> Result<()> {
+    let mut game = Game::Pig;
+    let mut games = DEFAULT_GAMES;
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--games" | "-g" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("Missing value for `{arg}`."))?;
+                games = value
+                    .parse()
+                    .ok()
+                    .filter(|&games| games > 0)
+                    .ok_or_else(|| anyhow!("`{value}` is not a number of games."))?;
+            }
+            _ => game = arg.parse()?,
+        }
+    }
+
+    let mut rng = rand::rng();
+    let rules = game.rules(&Config::default(), Strategy::Optimal);
+    let strategies = Strategy::ALL;
+
+    // wins[a][b] is how often strategy `a` beat strategy `b`.
+    let mut wins = [[0_u32; 4]; 4];
+
+    for (a, &first) in strategies.iter().enumerate() {
+        for (b, &second) in strategies.iter().enumerate().skip(a + 1) {
+            for round in 0..games {
+                // Take turns going first, since that is an advantage.
+                let outcome = if round % 2 == 0 {
+                    rules.simulate([first, second], &mut rng)
+                } else {
+                    rules
+                        .simulate([second, first], &mut rng)
+                        .map(|outcome| match outcome {
+                            Outcome::Won => Outcome::Lost,
+                            Outcome::Lost => Outcome::Won,
+                            outcome => outcome,
+                        })
+                };
+
+                match outcome {
+                    Some(Outcome::Won) => wins[a][b] += 1,
+                    Some(Outcome::Lost) => wins[b][a] += 1,
+                    Some(_) => {}
+                    None => {
+                        return Err(anyhow!(
+                            "{game} has no decisions to simulate. Try pig, yahtzee or liars."
+                        ))
+                    }
+                }
+            }
+        }
+    }
+
+    println!("{game}: win rates over {games} games per pairing");
+    println!();
+
+    let percent = |wins: u32, games: u32| format!("{:.1}%", wins as f64 * 100.0 / games as f64);
+
+    print!("{:<10}", "");
+    for strategy in strategies {
+        print!("{:>12}", format!("vs {strategy}"));
+    }
+    println!("{:>12}", "overall");
+
+    for (a, strategy) in strategies.iter().enumerate() {
+        print!("{:<10}", strategy.to_string());
+        for (b, &won) in wins[a].iter().enumerate() {
+            let cell = if a == b {
+                String::from("-")
+            } else {
+                percent(won, games)
+            };
+            print!("{cell:>12}");
+        }
+
+        let total: u32 = wins[a].iter().sum();
+        let played = games * (strategies.len() as u32 - 1);
+        println!("{:>12}", percent(total, played));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,301 @@
+use std::{fmt::Display, ops::RangeInclusive, str::FromStr};
+
+use anyhow::{anyhow, Result};
+
+use crate::secret::{Animals, Dates, Decimals, Integers, Letters, Scale};
+
+/// The most decimal places a decimal secret can have.
+const MAX_PRECISION: u32 = 6;
+
+/// The preset ranges a game can be played with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Difficulty {
+    /// 1 to 10.
+    Easy,
+    /// 1 to 100.
+    #[default]
+    Normal,
+    /// 1 to 1,000.
+    Hard,
+    /// 1 to 1,000,000.
+    Extreme,
+}
+
+impl Difficulty {
+    /// The range of numbers the secret is picked from.
+    pub fn range(&self) -> RangeInclusive<i64> {
+        match self {
+            Difficulty::Easy => 1..=10,
+            Difficulty::Normal => 1..=100,
+            Difficulty::Hard => 1..=1_000,
+            Difficulty::Extreme => 1..=1_000_000,
+        }
+    }
+
+    /// The strategy the computer uses in reverse mode, which gets better as
+    /// the difficulty goes up.
+    pub fn strategy(&self) -> Strategy {
+        match self {
+            Difficulty::Easy => Strategy::Random,
+            Difficulty::Normal => Strategy::Sloppy,
+            Difficulty::Hard | Difficulty::Extreme => Strategy::BinarySearch,
+        }
+    }
+}
+
+/// What kind of secret is guessed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Kind {
+    /// Whole numbers.
+    #[default]
+    Integer,
+    /// Numbers with a fixed number of decimal places.
+    Decimal,
+    /// A letter of the alphabet.
+    Letter,
+    /// An animal name, in alphabetical order.
+    Animal,
+    /// A date between 2000 and 2029.
+    Date,
+}
+
+impl Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Kind::Integer => "integer",
+                Kind::Decimal => "decimal",
+                Kind::Letter => "letter",
+                Kind::Animal => "animal",
+                Kind::Date => "date",
+            }
+        )
+    }
+}
+
+impl FromStr for Kind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "integer" | "number" => Ok(Kind::Integer),
+            "decimal" => Ok(Kind::Decimal),
+            "letter" => Ok(Kind::Letter),
+            "animal" | "word" => Ok(Kind::Animal),
+            "date" => Ok(Kind::Date),
+            _ => Err(anyhow!(
+                "Unknown kind `{s}`. Use integer, decimal, letter, animal or date."
+            )),
+        }
+    }
+}
+
+/// How the computer picks its guesses in reverse mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Always guesses the middle of the numbers that are still possible.
+    BinarySearch,
+    /// Guesses somewhere around the middle, wasting a guess now and then.
+    Sloppy,
+    /// Guesses any number that is still possible.
+    Random,
+}
+
+impl Display for Strategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Strategy::BinarySearch => "binary",
+                Strategy::Sloppy => "sloppy",
+                Strategy::Random => "random",
+            }
+        )
+    }
+}
+
+impl FromStr for Strategy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "binary" | "perfect" => Ok(Strategy::BinarySearch),
+            "sloppy" => Ok(Strategy::Sloppy),
+            "random" => Ok(Strategy::Random),
+            _ => Err(anyhow!(
+                "Unknown strategy `{s}`. Use binary, sloppy or random."
+            )),
+        }
+    }
+}
+
+impl Display for Difficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Difficulty::Easy => "easy",
+                Difficulty::Normal => "normal",
+                Difficulty::Hard => "hard",
+                Difficulty::Extreme => "extreme",
+            }
+        )
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "easy" => Ok(Difficulty::Easy),
+            "normal" => Ok(Difficulty::Normal),
+            "hard" => Ok(Difficulty::Hard),
+            "extreme" => Ok(Difficulty::Extreme),
+            _ => Err(anyhow!(
+                "Unknown difficulty `{s}`. Use easy, normal, hard or extreme."
+            )),
+        }
+    }
+}
+
+/// The settings a game is played with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// What kind of secret is guessed.
+    pub kind: Kind,
+    /// The range of numbers the secret is picked from. Only used for
+    /// integers and decimals.
+    pub range: RangeInclusive<i64>,
+    /// How many decimal places a decimal secret has.
+    pub precision: u32,
+    /// Whether the player only gets as many guesses as a perfect binary
+    /// search needs.
+    pub limited: bool,
+    /// How the computer guesses in reverse mode.
+    pub strategy: Strategy,
+    /// Whether guesses are answered with how close they are instead of
+    /// whether they are too high or too low.
+    pub hot_cold: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            kind: Kind::default(),
+            range: Difficulty::default().range(),
+            precision: 1,
+            limited: false,
+            strategy: Difficulty::default().strategy(),
+            hot_cold: false,
+        }
+    }
+}
+
+impl Config {
+    /// Builds a [`Config`] from command line arguments such as
+    /// `--difficulty hard`, `--min -5 --max 50`, `--kind decimal`,
+    /// `--precision 2`, `--limited`, `--hot-cold` and `--strategy binary`.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        let mut config = Config::default();
+        let (mut min, mut max) = (None, None);
+        let mut strategy = None;
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| anyhow!("Missing value for `{arg}`."))
+            };
+
+            match arg.as_str() {
+                "--difficulty" | "-d" => {
+                    let difficulty = value()?.parse::<Difficulty>()?;
+                    config.range = difficulty.range();
+                    config.strategy = difficulty.strategy();
+                }
+                "--strategy" | "-s" => strategy = Some(value()?.parse()?),
+                "--min" => min = Some(parse_number(&value()?)?),
+                "--max" => max = Some(parse_number(&value()?)?),
+                "--kind" | "-k" => config.kind = value()?.parse()?,
+                "--precision" | "-p" => {
+                    let input = value()?;
+                    config.precision = input
+                        .trim()
+                        .parse()
+                        .ok()
+                        .filter(|precision| (1..=MAX_PRECISION).contains(precision))
+                        .ok_or_else(|| {
+                            anyhow!(
+                                "The precision must be between 1 and {MAX_PRECISION}, not `{input}`."
+                            )
+                        })?;
+                }
+                "--limited" | "-l" => config.limited = true,
+                "--hot-cold" | "-c" => config.hot_cold = true,
+                _ => return Err(anyhow!("Unknown argument `{arg}`.")),
+            }
+        }
+
+        let min = min.unwrap_or(*config.range.start());
+        let max = max.unwrap_or(*config.range.end());
+
+        if min >= max {
+            return Err(anyhow!(
+                "The minimum ({min}) must be smaller than the maximum ({max})."
+            ));
+        }
+
+        // Keep the range small enough that every position fits in a usize,
+        // even with the most decimal places.
+        let limit = i64::MAX / 10_i64.pow(MAX_PRECISION) / 2;
+        if min < -limit || max > limit {
+            return Err(anyhow!("The range must stay between -{limit} and {limit}."));
+        }
+
+        config.range = min..=max;
+        config.strategy = strategy.unwrap_or(config.strategy);
+        Ok(config)
+    }
+
+    /// How many guesses the player gets, if the game is limited.
+    pub fn max_guesses(&self) -> Option<u32> {
+        self.limited.then(|| optimal_guesses(self.scale().len()))
+    }
+
+    /// The values the secret is picked from.
+    pub fn scale(&self) -> Box<dyn Scale> {
+        let (first, last) = (*self.range.start(), *self.range.end());
+
+        match self.kind {
+            Kind::Integer => Box::new(Integers { first, last }),
+            Kind::Decimal => Box::new(Decimals {
+                first,
+                last,
+                precision: self.precision,
+            }),
+            Kind::Letter => Box::new(Letters),
+            Kind::Animal => Box::new(Animals),
+            Kind::Date => Box::new(Dates::DEFAULT),
+        }
+    }
+}
+
+/// The most guesses a perfect binary search needs to find any number in
+/// `count` values, i.e. `ceil(log2(count + 1))`.
+pub fn optimal_guesses(count: usize) -> u32 {
+    usize::BITS - count.leading_zeros()
+}
+
+/// Parses a range bound.
+fn parse_number(input: &str) -> Result<i64> {
+    input
+        .trim()
+        .replace([',', '_'], "")
+        .parse()
+        .map_err(|_| anyhow!("`{input}` is not a whole number."))
+}
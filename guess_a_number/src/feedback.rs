@@ -0,0 +1,190 @@
+use std::{fmt::Display, ops::RangeInclusive};
+
+use crate::secret::Scale;
+
+/// Width of the number line, in characters.
+const BAR_WIDTH: usize = 50;
+
+/// How close a guess is to the secret in hot/cold mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Temperature {
+    Boiling,
+    Hot,
+    Warm,
+    Cool,
+    Cold,
+}
+
+impl Temperature {
+    /// All temperatures, from hottest to coldest.
+    const ALL: [Temperature; 5] = [
+        Temperature::Boiling,
+        Temperature::Hot,
+        Temperature::Warm,
+        Temperature::Cool,
+        Temperature::Cold,
+    ];
+
+    /// Finds the temperature of a guess `distance` away from the secret in a
+    /// scale of `count` values.
+    pub fn of(distance: usize, count: usize) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|temperature| {
+                temperature
+                    .max_distance(count)
+                    .is_none_or(|max| distance <= max)
+            })
+            .unwrap_or(Temperature::Cold)
+    }
+
+    /// The furthest a guess with this temperature can be from the secret,
+    /// or `None` if there is no limit.
+    pub fn max_distance(&self, count: usize) -> Option<usize> {
+        let percent = match self {
+            Temperature::Boiling => 1,
+            Temperature::Hot => 5,
+            Temperature::Warm => 15,
+            Temperature::Cool => 30,
+            Temperature::Cold => return None,
+        };
+
+        // Scales of decimals can have nearly `usize::MAX` values.
+        Some(((count as u128 * percent / 100) as usize).max(1))
+    }
+}
+
+impl Display for Temperature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Temperature::Boiling => "Boiling!",
+                Temperature::Hot => "Hot!",
+                Temperature::Warm => "Warm.",
+                Temperature::Cool => "Cool.",
+                Temperature::Cold => "Cold.",
+            }
+        )
+    }
+}
+
+/// Whether a guess got closer to the secret than the one before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Warmer,
+    Colder,
+    Same,
+}
+
+impl Trend {
+    /// Compares the distances of the previous and the current guess.
+    pub fn between(previous: usize, guess: usize, secret: usize) -> Self {
+        match guess.abs_diff(secret).cmp(&previous.abs_diff(secret)) {
+            std::cmp::Ordering::Less => Trend::Warmer,
+            std::cmp::Ordering::Equal => Trend::Same,
+            std::cmp::Ordering::Greater => Trend::Colder,
+        }
+    }
+
+    /// Narrows `feasible` to the numbers that would have given this trend.
+    pub fn narrow(
+        &self,
+        previous: usize,
+        guess: usize,
+        feasible: &RangeInclusive<usize>,
+    ) -> RangeInclusive<usize> {
+        if previous == guess {
+            return feasible.clone();
+        }
+
+        let sum = previous + guess;
+        // Numbers above the midpoint are closer to the larger of the two
+        // guesses, numbers below it to the smaller one.
+        let above = sum / 2 + 1..=usize::MAX;
+        let below = 0..=(sum - 1) / 2;
+
+        let allowed = match (self, guess > previous) {
+            (Trend::Warmer, true) | (Trend::Colder, false) => above,
+            (Trend::Warmer, false) | (Trend::Colder, true) => below,
+            (Trend::Same, _) => sum / 2..=sum / 2,
+        };
+
+        intersect(feasible, &allowed)
+    }
+}
+
+impl Display for Trend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Trend::Warmer => "Getting warmer.",
+                Trend::Colder => "Getting colder.",
+                Trend::Same => "No warmer, no colder.",
+            }
+        )
+    }
+}
+
+/// The numbers that are in both ranges. The result is empty if they don't
+/// overlap.
+pub fn intersect(a: &RangeInclusive<usize>, b: &RangeInclusive<usize>) -> RangeInclusive<usize> {
+    *a.start().max(b.start())..=*a.end().min(b.end())
+}
+
+/// Draws the scale as a number line with the `feasible` positions filled
+/// in, e.g. `1 [·····█████···] 100 (36 - 62 left)`.
+pub fn number_line(scale: &dyn Scale, feasible: &RangeInclusive<usize>) -> String {
+    let count = scale.len() as u128;
+    let width = (BAR_WIDTH as u128).min(count);
+
+    let bar: String = (0..width)
+        .map(|column| {
+            // The positions covered by this column.
+            let first = (column * count / width) as usize;
+            let last = ((column + 1) * count / width - 1) as usize;
+
+            if first <= *feasible.end() && last >= *feasible.start() {
+                '█'
+            } else {
+                '·'
+            }
+        })
+        .collect();
+
+    let left = if feasible.start() == feasible.end() {
+        format!("only {} left", scale.format(*feasible.start()))
+    } else {
+        format!(
+            "{} - {} left",
+            scale.format(*feasible.start()),
+            scale.format(*feasible.end())
+        )
+    };
+
+    format!(
+        "{} [{bar}] {} ({left})",
+        scale.format(0),
+        scale.format(scale.len() - 1)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn temperatures_work_at_the_largest_range() {
+        let args = "--kind decimal --precision 6 --min -4000000000000 --max 4000000000000";
+        let config = Config::from_args(args.split(' ').map(String::from)).unwrap();
+        let count = config.scale().len();
+
+        assert_eq!(Temperature::of(0, count), Temperature::Boiling);
+        assert_eq!(Temperature::of(count / 20, count), Temperature::Hot);
+        assert_eq!(Temperature::of(count - 1, count), Temperature::Cold);
+    }
+}
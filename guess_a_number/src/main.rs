@@ -1,82 +1,276 @@
-use std::{io::Write, ops::Range};
+mod config;
+mod feedback;
+mod multiplayer;
+mod reverse;
+mod secret;
+mod stats;
+
+use std::{io::Write, ops::RangeInclusive};
 
 use anyhow::{anyhow, Ok, Result};
+use config::Config;
+use feedback::{Temperature, Trend};
 use rand::Rng;
+use secret::Scale;
+use stats::{Outcome, Stats};
 
 /// Structure containing all application data
-#[derive(Debug, Default)]
+#[derive(Debug)]
 struct Game {
+    /// The values the secret is picked from. The secret and the guesses
+    /// are positions on it.
+    scale: Box<dyn Scale>,
     secret: usize,
-    range: Range<usize>,
+    /// The positions that are still possible after the feedback so far.
+    feasible: RangeInclusive<usize>,
+    max_guesses: Option<u32>,
+    /// Whether guesses are answered with hot/cold feedback.
+    hot_cold: bool,
+    /// The last valid guess, to tell whether the player is getting warmer.
+    previous: Option<usize>,
     guesses: usize,
     input: String,
 }
 
 impl Game {
     /// Creates a new [`Game`] object
-    fn new() -> Self {
-        let range = 1..100;
-        let secret = rand::thread_rng().gen_range(range.clone());
+    fn new(config: &Config) -> Self {
+        let scale = config.scale();
+        let secret = rand::rng().random_range(0..scale.len());
 
         Self {
             secret,
-            range,
+            feasible: 0..=scale.len() - 1,
+            scale,
+            max_guesses: config.max_guesses(),
+            hot_cold: config.hot_cold,
+            previous: None,
             guesses: 0,
             input: String::default(),
         }
     }
 
     /// Plays a loop of the [`Game`] object
-    fn play(&mut self) -> Result<()> {
+    fn play(&mut self) -> Result<Outcome> {
         loop {
-            print!(
-                "Guess a number ({} - {}): ",
-                self.range.start,
-                self.range.end - 1
+            if let Some(outcome) = self.turn(None)? {
+                return Ok(outcome);
+            }
+        }
+    }
+
+    /// Asks for guesses until one is counted. Returns the [`Outcome`] if the
+    /// game is over. The `player` is addressed in the prompt when given.
+    fn turn(&mut self, player: Option<&str>) -> Result<Option<Outcome>> {
+        if self
+            .max_guesses
+            .is_some_and(|max_guesses| self.guesses >= max_guesses as usize)
+        {
+            println!(
+                "You ran out of guesses. The {} was {}.",
+                self.scale.noun(),
+                self.scale.format(self.secret)
             );
+            return Ok(Some(Outcome::Lost));
+        }
+
+        loop {
+            let addressee = player
+                .map(|name| format!("{name}, g"))
+                .unwrap_or("G".into());
+
+            match self.max_guesses {
+                Some(max_guesses) => print!(
+                    "{addressee}uess {} ({}, guess {} of {max_guesses}): ",
+                    self.scale.a_noun(),
+                    self.scale.bounds(),
+                    self.guesses + 1
+                ),
+                None => print!(
+                    "{addressee}uess {} ({}): ",
+                    self.scale.a_noun(),
+                    self.scale.bounds()
+                ),
+            }
             std::io::stdout().flush()?;
-            std::io::stdin().read_line(&mut self.input)?;
 
-            let flag = self.check();
+            self.input = String::new();
+            if std::io::stdin().read_line(&mut self.input)? == 0 {
+                // The input was closed so nobody is left to play.
+                println!();
+                return Ok(Some(Outcome::Quit));
+            }
 
-            if let Err(error) = flag {
-                println!("{error}");
-            } else if let std::result::Result::Ok(correct_guess) = flag {
-                if correct_guess {
-                    break;
+            match self.check() {
+                Err(error) => println!("{error}"),
+                std::result::Result::Ok(true) => {
+                    return Ok(Some(Outcome::Won {
+                        guesses: self.guesses,
+                    }))
                 }
+                std::result::Result::Ok(false) => return Ok(None),
             }
+        }
+    }
 
-            self.guesses += 1;
-            self.input = String::new();
+    /// Check if input was valid. Only valid guesses inside the range are
+    /// counted.
+    fn check(&mut self) -> Result<bool> {
+        let input = self.input.trim();
+        let position = self.scale.parse(input)?;
+
+        if !(0..self.scale.len() as i64).contains(&position) {
+            return Err(anyhow!(
+                "{input} is out of range. Guess between {}.",
+                self.scale.bounds().replace(" - ", " and ")
+            ));
         }
-        Ok(())
+
+        let valid = position as usize;
+
+        self.guesses += 1;
+
+        if valid < *self.feasible.start() {
+            println!(
+                "Careful: you already know the {} is at least {}.",
+                self.scale.noun(),
+                self.scale.format(*self.feasible.start())
+            );
+        } else if valid > *self.feasible.end() {
+            println!(
+                "Careful: you already know the {} is at most {}.",
+                self.scale.noun(),
+                self.scale.format(*self.feasible.end())
+            );
+        }
+
+        if valid == self.secret {
+            println!("Congrats! You are correct!");
+            println!(
+                "You completed the game in {} {}.",
+                self.guesses,
+                if self.guesses == 1 {
+                    "guess"
+                } else {
+                    "guesses"
+                }
+            );
+            return Ok(true);
+        }
+
+        if self.hot_cold {
+            self.hot_cold_feedback(valid);
+        } else if valid < self.secret {
+            println!("Too Low!");
+            self.feasible = (valid + 1).max(*self.feasible.start())..=*self.feasible.end();
+        } else {
+            println!("Too High");
+            self.feasible = *self.feasible.start()..=(valid - 1).min(*self.feasible.end());
+        }
+
+        self.previous = Some(valid);
+        println!(
+            "{}",
+            feedback::number_line(self.scale.as_ref(), &self.feasible)
+        );
+
+        Ok(false)
     }
 
-    /// Check if input was valid
-    fn check(&self) -> Result<bool> {
-        let valid = match self.input.trim().parse::<usize>() {
-            std::result::Result::Ok(value) => value,
-            Err(_) => return Err(anyhow!("The given input was invalid. Use only numbers.")),
-        };
-
-        match valid.cmp(&self.secret) {
-            std::cmp::Ordering::Less => println!("Too Low!"),
-            std::cmp::Ordering::Equal => {
-                println!("Congrats! You are correct!");
-                println!("You completed the game in {} guesses.", self.guesses);
-                return Ok(true);
+    /// Tells the player how close `guess` is and whether it got closer than
+    /// the last guess, then narrows down the numbers that are still
+    /// possible.
+    fn hot_cold_feedback(&mut self, guess: usize) {
+        let count = self.scale.len();
+        let temperature = Temperature::of(guess.abs_diff(self.secret), count);
+
+        if let Some(max) = temperature.max_distance(count) {
+            let near = guess.saturating_sub(max)..=guess.saturating_add(max);
+            self.feasible = feedback::intersect(&self.feasible, &near);
+        }
+
+        match self.previous {
+            Some(previous) => {
+                let trend = Trend::between(previous, guess, self.secret);
+                println!("{temperature} {trend}");
+                self.feasible = trend.narrow(previous, guess, &self.feasible);
             }
-            std::cmp::Ordering::Greater => println!("Too High"),
-        };
+            None => println!("{temperature}"),
+        }
 
-        Ok(false)
+        // The secret can't be one of the wrong guesses, which only matters
+        // when they are at the edge of the numbers that are left.
+        for wrong in [Some(guess), self.previous].into_iter().flatten() {
+            if wrong == *self.feasible.start() {
+                self.feasible = wrong + 1..=*self.feasible.end();
+            } else if wrong == *self.feasible.end() {
+                self.feasible = *self.feasible.start()..=wrong - 1;
+            }
+        }
     }
 }
 
 fn main() -> Result<()> {
-    let mut game = Game::new();
-    game.play()?;
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+
+    if args.first().is_some_and(|arg| arg == "reverse") {
+        let config = Config::from_args(args.drain(1..))?;
+        return reverse::play(&config);
+    }
+
+    if let Some(mode @ ("hotseat" | "race")) = args.first().map(String::as_str) {
+        let race = mode == "race";
+        let config = Config::from_args(args.drain(1..))?;
+        return multiplayer::play(&config, race);
+    }
+
+    let config = Config::from_args(args)?;
+
+    if let Some(max_guesses) = config.max_guesses() {
+        println!("You have {max_guesses} guesses, just enough for a perfect binary search.");
+    }
+
+    let optimal = config::optimal_guesses(config.scale().len());
+    let mut stats = Stats::new(optimal);
 
+    loop {
+        let outcome = Game::new(&config).play()?;
+
+        if let Outcome::Won { guesses } = outcome {
+            println!("{}", stats::efficiency(guesses, optimal));
+        }
+
+        stats.record(outcome);
+
+        if outcome == Outcome::Quit || !play_again()? {
+            break;
+        }
+
+        println!();
+    }
+
+    println!();
+    print!("{stats}");
+    println!("Thanks for playing!");
     Ok(())
 }
+
+/// Asks whether the player wants another game. A closed input counts as no.
+fn play_again() -> Result<bool> {
+    loop {
+        print!("Do you want to play again? (yes/no) ");
+        std::io::stdout().flush()?;
+
+        let mut input = String::new();
+        if std::io::stdin().read_line(&mut input)? == 0 {
+            println!();
+            return Ok(false);
+        }
+
+        match input.trim().to_lowercase().as_str() {
+            "y" | "yes" => return Ok(true),
+            "n" | "no" => return Ok(false),
+            _ => println!("Please answer yes or no."),
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use std::io::Write;
+
+use anyhow::Result;
+
+use crate::{config::Config, stats::Outcome, Game};
+
+/// The fewest and most players a multiplayer game can have.
+const PLAYERS: std::ops::RangeInclusive<usize> = 2..=4;
+
+/// Plays with several players on one terminal. In a hot-seat game everyone
+/// takes turns guessing the same secret and whoever finds it wins. In a race
+/// every player gets their own secret and the fewest guesses wins.
+pub fn play(config: &Config, race: bool) -> Result<()> {
+    if race {
+        println!("Race mode: every player gets their own secret and makes one guess");
+        println!("per turn. The fewest guesses wins.");
+    } else {
+        println!("Hot-seat mode: take turns guessing the same secret. Everyone sees");
+        println!("the answers, and whoever finds the number wins.");
+    }
+    println!();
+
+    let Some(names) = read_names()? else {
+        return Ok(());
+    };
+    println!();
+
+    let results = if race {
+        play_race(config, &names)?
+    } else {
+        play_hotseat(config, &names)?
+    };
+
+    if let Some(results) = results {
+        println!();
+        print_results(&names, &results);
+    }
+
+    Ok(())
+}
+
+/// How one player is doing in a game.
+#[derive(Debug, Clone, Copy, Default)]
+struct Standing {
+    guesses: usize,
+    outcome: Option<Outcome>,
+}
+
+/// Everyone guesses the same secret. Returns `None` if the input was closed.
+fn play_hotseat(config: &Config, names: &[String]) -> Result<Option<Vec<Standing>>> {
+    let mut game = Game::new(config);
+    let mut results = vec![Standing::default(); names.len()];
+
+    loop {
+        for (player, name) in names.iter().enumerate() {
+            match game.turn(Some(name))? {
+                Some(Outcome::Quit) => return Ok(None),
+                Some(Outcome::Won { .. }) => {
+                    results[player].guesses += 1;
+                    for (other, result) in results.iter_mut().enumerate() {
+                        result.outcome = Some(if other == player {
+                            Outcome::Won {
+                                guesses: result.guesses,
+                            }
+                        } else {
+                            Outcome::Lost
+                        });
+                    }
+                    return Ok(Some(results));
+                }
+                Some(Outcome::Lost) => {
+                    for result in &mut results {
+                        result.outcome = Some(Outcome::Lost);
+                    }
+                    return Ok(Some(results));
+                }
+                None => results[player].guesses += 1,
+            }
+        }
+    }
+}
+
+/// Everyone guesses their own secret, one guess per turn. Returns `None` if
+/// the input was closed.
+fn play_race(config: &Config, names: &[String]) -> Result<Option<Vec<Standing>>> {
+    let mut games: Vec<Game> = names.iter().map(|_| Game::new(config)).collect();
+    let mut results = vec![Standing::default(); names.len()];
+
+    while results.iter().any(|result| result.outcome.is_none()) {
+        for (player, name) in names.iter().enumerate() {
+            if results[player].outcome.is_some() {
+                continue;
+            }
+
+            // The race goes to the fewest guesses, so a player who has
+            // used as many as the current leader is out.
+            let best = results
+                .iter()
+                .filter_map(|result| match result.outcome {
+                    Some(Outcome::Won { guesses }) => Some(guesses),
+                    _ => None,
+                })
+                .min();
+
+            if best.is_some_and(|best| results[player].guesses >= best) {
+                println!("{name} can't win any more.");
+                results[player].outcome = Some(Outcome::Lost);
+                continue;
+            }
+
+            let outcome = games[player].turn(Some(name))?;
+
+            if outcome == Some(Outcome::Quit) {
+                return Ok(None);
+            }
+
+            results[player].guesses = games[player].guesses;
+            results[player].outcome = outcome;
+            println!();
+        }
+    }
+
+    Ok(Some(results))
+}
+
+/// Asks how many players there are and what they are called. Returns `None`
+/// if the input was closed.
+fn read_names() -> Result<Option<Vec<String>>> {
+    let count = loop {
+        let Some(input) = read_line(&format!(
+            "How many players? ({} - {}) ",
+            PLAYERS.start(),
+            PLAYERS.end()
+        ))?
+        else {
+            return Ok(None);
+        };
+
+        match input.parse::<usize>() {
+            Ok(count) if PLAYERS.contains(&count) => break count,
+            _ => println!(
+                "Please enter a number between {} and {}.",
+                PLAYERS.start(),
+                PLAYERS.end()
+            ),
+        }
+    };
+
+    let mut names = vec![];
+    for player in 1..=count {
+        let Some(name) = read_line(&format!("Name of player {player}: "))? else {
+            return Ok(None);
+        };
+
+        names.push(if name.is_empty() {
+            format!("Player {player}")
+        } else {
+            name
+        });
+    }
+
+    Ok(Some(names))
+}
+
+/// Prints `prompt` and reads a trimmed line. Returns `None` if the input was
+/// closed.
+fn read_line(prompt: &str) -> Result<Option<String>> {
+    print!("{prompt}");
+    std::io::stdout().flush()?;
+
+    let mut input = String::new();
+    if std::io::stdin().read_line(&mut input)? == 0 {
+        println!();
+        return Ok(None);
+    }
+
+    Ok(Some(input.trim().to_string()))
+}
+
+/// Prints a table with every player's guesses and result.
+fn print_results(names: &[String], results: &[Standing]) {
+    let best = results
+        .iter()
+        .filter_map(|result| match result.outcome {
+            Some(Outcome::Won { guesses }) => Some(guesses),
+            _ => None,
+        })
+        .min();
+
+    let winners = results
+        .iter()
+        .filter(|result| result.outcome == best.map(|guesses| Outcome::Won { guesses }))
+        .count();
+    let width = names
+        .iter()
+        .map(|name| name.chars().count())
+        .chain(["Player".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!("{:<width$}  Guesses  Result", "Player");
+    for (name, result) in names.iter().zip(results) {
+        let verdict = match result.outcome {
+            Some(Outcome::Won { guesses }) if Some(guesses) == best && winners > 1 => "Tied",
+            Some(Outcome::Won { guesses }) if Some(guesses) == best => "Winner",
+            Some(Outcome::Won { .. }) => "Found it",
+            _ => "-",
+        };
+
+        println!("{name:<width$}  {:>7}  {verdict}", result.guesses);
+    }
+
+    if best.is_none() {
+        println!("Nobody found the number.");
+    }
+}
@@ -0,0 +1,176 @@
+use std::{io::Write, ops::RangeInclusive};
+
+use anyhow::Result;
+use rand::Rng;
+
+use crate::{
+    config::{Config, Strategy},
+    secret::Scale,
+};
+
+/// An answer the player gave to one of the computer's guesses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Answer {
+    Higher,
+    Lower,
+    Correct,
+}
+
+/// The answer that set one of the bounds of the possible numbers.
+#[derive(Debug, Clone)]
+struct Reason {
+    guess_number: usize,
+    guess: String,
+    answer: Answer,
+}
+
+impl Reason {
+    /// Describes the answer, e.g. `said 40 was too low (guess #2)`.
+    fn describe(&self) -> String {
+        format!(
+            "said {} was too {} (guess #{})",
+            self.guess,
+            if self.answer == Answer::Higher {
+                "low"
+            } else {
+                "high"
+            },
+            self.guess_number
+        )
+    }
+}
+
+/// Plays the game the other way around: the player thinks of a secret and
+/// tells the computer whether its guesses are too high or too low.
+pub fn play(config: &Config) -> Result<()> {
+    let scale = config.scale();
+
+    println!(
+        "Think of {} between {}.",
+        scale.a_noun(),
+        scale.bounds().replace(" - ", " and ")
+    );
+    println!("Answer each of my guesses with [h]igher, [l]ower or [c]orrect.");
+    println!();
+
+    let mut rng = rand::rng();
+    let mut feasible = 0..=scale.len() - 1;
+    let (mut low_reason, mut high_reason): (Option<Reason>, Option<Reason>) = (None, None);
+    let mut guess_number = 1;
+
+    loop {
+        let guess = pick_guess(config.strategy, &feasible, &mut rng);
+
+        let Some(answer) = read_answer(guess_number, &scale.format(guess))? else {
+            println!();
+            return Ok(());
+        };
+
+        let reason = Reason {
+            guess_number,
+            guess: scale.format(guess),
+            answer,
+        };
+
+        match answer {
+            Answer::Correct => {
+                println!(
+                    "I got it in {guess_number} {}!",
+                    if guess_number == 1 {
+                        "guess"
+                    } else {
+                        "guesses"
+                    }
+                );
+                return Ok(());
+            }
+            Answer::Higher if guess >= *feasible.end() => {
+                report_contradiction(Some(reason), high_reason, scale.as_ref());
+                return Ok(());
+            }
+            Answer::Lower if guess <= *feasible.start() => {
+                report_contradiction(low_reason, Some(reason), scale.as_ref());
+                return Ok(());
+            }
+            Answer::Higher => {
+                feasible = guess + 1..=*feasible.end();
+                low_reason = Some(reason);
+            }
+            Answer::Lower => {
+                feasible = *feasible.start()..=guess - 1;
+                high_reason = Some(reason);
+            }
+        }
+
+        guess_number += 1;
+    }
+}
+
+/// Picks the next guess from the numbers that are still possible.
+fn pick_guess(strategy: Strategy, feasible: &RangeInclusive<usize>, rng: &mut impl Rng) -> usize {
+    let (low, high) = (*feasible.start(), *feasible.end());
+    let middle = low + (high - low) / 2;
+
+    match strategy {
+        Strategy::BinarySearch => middle,
+        Strategy::Sloppy => {
+            // Somewhere in the middle half of what is left.
+            let spread = (high - low) / 4;
+            rng.random_range(middle - spread.min(middle - low)..=middle + spread)
+        }
+        Strategy::Random => rng.random_range(feasible.clone()),
+    }
+}
+
+/// Asks the player about `guess` until they give a valid answer. Returns
+/// `None` once the input is closed.
+fn read_answer(guess_number: usize, guess: &str) -> Result<Option<Answer>> {
+    loop {
+        print!("Guess #{guess_number}: is it {guess}? ");
+        std::io::stdout().flush()?;
+
+        let mut input = String::new();
+        if std::io::stdin().read_line(&mut input)? == 0 {
+            return Ok(None);
+        }
+
+        match input.trim().to_lowercase().as_str() {
+            "h" | "higher" | "+" | "up" => return Ok(Some(Answer::Higher)),
+            "l" | "lower" | "-" | "down" => return Ok(Some(Answer::Lower)),
+            "c" | "correct" | "=" | "yes" | "y" => return Ok(Some(Answer::Correct)),
+            _ => println!("Please answer with [h]igher, [l]ower or [c]orrect."),
+        }
+    }
+}
+
+/// Explains which answers can't both be true. A missing reason means the
+/// bound is the edge of the range itself.
+fn report_contradiction(low: Option<Reason>, high: Option<Reason>, scale: &dyn Scale) {
+    println!("That can't be right!");
+
+    match (low, high) {
+        (Some(low), Some(high)) => println!(
+            "Earlier you {}, but then you {}.",
+            low.describe(),
+            high.describe()
+        ),
+        (Some(low), None) => println!(
+            "You {}, but the {} can't be above {}.",
+            low.describe(),
+            scale.noun(),
+            scale.format(scale.len() - 1)
+        ),
+        (None, Some(high)) => println!(
+            "You {}, but the {} can't be below {}.",
+            high.describe(),
+            scale.noun(),
+            scale.format(0)
+        ),
+        (None, None) => {}
+    }
+
+    println!(
+        "No {} fits all of your answers, so one of them was wrong.",
+        scale.noun()
+    );
+}
@@ -0,0 +1,393 @@
+use std::fmt::Debug;
+
+use anyhow::{anyhow, Result};
+
+/// The animals that can be guessed, in alphabetical order.
+const ANIMALS: [&str; 60] = [
+    "alligator",
+    "alpaca",
+    "ant",
+    "badger",
+    "bat",
+    "bear",
+    "beaver",
+    "bison",
+    "camel",
+    "cat",
+    "cheetah",
+    "chicken",
+    "cow",
+    "crab",
+    "crow",
+    "deer",
+    "dog",
+    "dolphin",
+    "donkey",
+    "duck",
+    "eagle",
+    "eel",
+    "elephant",
+    "falcon",
+    "ferret",
+    "flamingo",
+    "fox",
+    "frog",
+    "giraffe",
+    "goat",
+    "gorilla",
+    "hamster",
+    "hedgehog",
+    "horse",
+    "hyena",
+    "jaguar",
+    "kangaroo",
+    "koala",
+    "lemur",
+    "leopard",
+    "lion",
+    "llama",
+    "lobster",
+    "mole",
+    "monkey",
+    "moose",
+    "mouse",
+    "octopus",
+    "otter",
+    "owl",
+    "panda",
+    "penguin",
+    "rabbit",
+    "raccoon",
+    "shark",
+    "sheep",
+    "tiger",
+    "turtle",
+    "walrus",
+    "zebra",
+];
+
+/// An ordered set of values a secret can be picked from. Every value has a
+/// position, so the game only ever compares and narrows down positions and
+/// works the same for every kind of secret.
+pub trait Scale: Debug {
+    /// What is being guessed, e.g. `number` or `date`.
+    fn noun(&self) -> &'static str;
+
+    /// How many values there are.
+    fn len(&self) -> usize;
+
+    /// Reads a guess and returns its position. Values that are well formed
+    /// but outside the scale get a position before the first or after the
+    /// last value.
+    fn parse(&self, input: &str) -> Result<i64>;
+
+    /// Writes the value at `position`.
+    fn format(&self, position: usize) -> String;
+
+    /// The noun with its article, e.g. `an animal`.
+    fn a_noun(&self) -> String {
+        let noun = self.noun();
+
+        if noun.starts_with(['a', 'e', 'i', 'o', 'u']) {
+            format!("an {noun}")
+        } else {
+            format!("a {noun}")
+        }
+    }
+
+    /// The first and last value, e.g. `1 - 100`.
+    fn bounds(&self) -> String {
+        format!("{} - {}", self.format(0), self.format(self.len() - 1))
+    }
+}
+
+/// Whole numbers, which may be negative.
+#[derive(Debug)]
+pub struct Integers {
+    pub first: i64,
+    pub last: i64,
+}
+
+impl Scale for Integers {
+    fn noun(&self) -> &'static str {
+        "number"
+    }
+
+    fn len(&self) -> usize {
+        (self.last - self.first) as usize + 1
+    }
+
+    fn parse(&self, input: &str) -> Result<i64> {
+        let value = input
+            .replace([',', '_'], "")
+            .parse::<i64>()
+            .map_err(|_| anyhow!("The given input was invalid. Use only whole numbers."))?;
+
+        Ok(value.saturating_sub(self.first))
+    }
+
+    fn format(&self, position: usize) -> String {
+        (self.first + position as i64).to_string()
+    }
+}
+
+/// Decimal numbers with a fixed number of decimal places.
+#[derive(Debug)]
+pub struct Decimals {
+    pub first: i64,
+    pub last: i64,
+    /// How many decimal places the numbers have.
+    pub precision: u32,
+}
+
+impl Decimals {
+    /// How many steps there are between two whole numbers.
+    fn steps(&self) -> i64 {
+        10_i64.pow(self.precision)
+    }
+}
+
+impl Scale for Decimals {
+    fn noun(&self) -> &'static str {
+        "number"
+    }
+
+    fn len(&self) -> usize {
+        ((self.last - self.first) * self.steps()) as usize + 1
+    }
+
+    fn parse(&self, input: &str) -> Result<i64> {
+        let invalid = || {
+            anyhow!(
+                "The given input was invalid. Use a number with at most {} decimal places.",
+                self.precision
+            )
+        };
+
+        let (negative, digits) = match input.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, input),
+        };
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+        // The sign was already taken off, so only digits are left. Either
+        // side of the point may be left out, like in `.5` or `5.`.
+        if (whole.is_empty() && fraction.is_empty())
+            || !whole.chars().all(|c| c.is_ascii_digit())
+            || fraction.len() > self.precision as usize
+            || !fraction.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let whole = match whole {
+            "" => 0,
+            whole => whole.parse::<i64>().map_err(|_| invalid())?,
+        };
+        let fraction = format!("{fraction:0<width$}", width = self.precision as usize);
+        let fraction = fraction.parse::<i64>().unwrap_or(0);
+        let steps = whole.saturating_mul(self.steps()).saturating_add(fraction);
+
+        Ok(if negative { -steps } else { steps }.saturating_sub(self.first * self.steps()))
+    }
+
+    fn format(&self, position: usize) -> String {
+        let steps = self.first * self.steps() + position as i64;
+        let sign = if steps < 0 { "-" } else { "" };
+        let (whole, fraction) = (steps.abs() / self.steps(), steps.abs() % self.steps());
+
+        format!(
+            "{sign}{whole}.{fraction:0width$}",
+            width = self.precision as usize
+        )
+    }
+}
+
+/// The letters of the alphabet.
+#[derive(Debug)]
+pub struct Letters;
+
+impl Scale for Letters {
+    fn noun(&self) -> &'static str {
+        "letter"
+    }
+
+    fn len(&self) -> usize {
+        26
+    }
+
+    fn parse(&self, input: &str) -> Result<i64> {
+        match input.to_ascii_lowercase().as_bytes() {
+            &[letter @ b'a'..=b'z'] => Ok((letter - b'a') as i64),
+            _ => Err(anyhow!("The given input was invalid. Use a single letter.")),
+        }
+    }
+
+    fn format(&self, position: usize) -> String {
+        char::from(b'a' + position as u8).to_string()
+    }
+}
+
+/// Animal names in alphabetical order.
+#[derive(Debug)]
+pub struct Animals;
+
+impl Scale for Animals {
+    fn noun(&self) -> &'static str {
+        "animal"
+    }
+
+    fn len(&self) -> usize {
+        ANIMALS.len()
+    }
+
+    fn parse(&self, input: &str) -> Result<i64> {
+        ANIMALS
+            .binary_search(&input.to_lowercase().as_str())
+            .map(|position| position as i64)
+            .map_err(|_| anyhow!("I don't know the animal `{input}`. Try another one."))
+    }
+
+    fn format(&self, position: usize) -> String {
+        ANIMALS[position].to_string()
+    }
+}
+
+/// Calendar dates, written as `YYYY-MM-DD`.
+#[derive(Debug)]
+pub struct Dates {
+    /// The first and last date, as days since 1970-01-01.
+    pub first: i64,
+    pub last: i64,
+}
+
+impl Dates {
+    /// 2000-01-01 to 2029-12-31.
+    pub const DEFAULT: Dates = Dates {
+        first: 10_957,
+        last: 21_914,
+    };
+}
+
+impl Scale for Dates {
+    fn noun(&self) -> &'static str {
+        "date"
+    }
+
+    fn len(&self) -> usize {
+        (self.last - self.first) as usize + 1
+    }
+
+    fn parse(&self, input: &str) -> Result<i64> {
+        let invalid = || anyhow!("The given input was invalid. Use a date like 2015-06-30.");
+
+        let parts: Vec<i64> = input
+            .splitn(3, '-')
+            .map(|part| part.parse().map_err(|_| invalid()))
+            .collect::<Result<_>>()?;
+        let &[year, month, day] = parts.as_slice() else {
+            return Err(invalid());
+        };
+
+        let not_real = || anyhow!("{input} is not a real date.");
+
+        if !(1..=9999).contains(&year) || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+            return Err(not_real());
+        }
+
+        let days = days_from_date(year, month, day);
+
+        // Dates like the 31st of February roll over into the next month.
+        if date(days) != (year, month, day) {
+            return Err(not_real());
+        }
+
+        Ok(days - self.first)
+    }
+
+    fn format(&self, position: usize) -> String {
+        let (year, month, day) = date(self.first + position as i64);
+
+        format!("{year:04}-{month:02}-{day:02}")
+    }
+}
+
+/// Counts the days from 1970-01-01 to a date. This and [`date`] are Howard
+/// Hinnant's `days_from_civil` and `civil_from_days`, which bagels also
+/// uses to show the date of its daily puzzle.
+fn days_from_date(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Converts days since 1970-01-01 to a year, month and day.
+fn date(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day_of_month = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+
+    (
+        year_of_era + era * 400 + i64::from(month <= 2),
+        month,
+        day_of_month,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decimals_parse_with_one_sign_and_optional_whole_part() {
+        let scale = Decimals {
+            first: -10,
+            last: 10,
+            precision: 1,
+        };
+        let value = |input: &str| scale.parse(input).ok().map(|position| position - 100);
+
+        assert_eq!(value("5"), Some(50));
+        assert_eq!(value("-5.5"), Some(-55));
+        assert_eq!(value("5."), Some(50));
+        assert_eq!(value(".5"), Some(5));
+        assert_eq!(value("-.5"), Some(-5));
+
+        for input in ["", ".", "-", "-.", "--5", "-+5", "+5", "5.55", "5.-5"] {
+            assert_eq!(value(input), None, "{input}");
+        }
+    }
+
+    #[test]
+    fn dates_round_trip_through_days() {
+        assert_eq!(days_from_date(1970, 1, 1), 0);
+        assert_eq!(days_from_date(2000, 3, 1), 11_017);
+
+        for days in days_from_date(1, 1, 1)..=days_from_date(9999, 12, 31) {
+            let (year, month, day) = date(days);
+            assert_eq!(days_from_date(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn leap_days_only_exist_in_leap_years() {
+        for (year, leap) in [(1900, false), (2000, true), (2023, false), (2024, true)] {
+            assert_eq!(date(days_from_date(year, 2, 29)) == (year, 2, 29), leap);
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt::Display;
+
+/// How a game ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Won {
+        guesses: usize,
+    },
+    Lost,
+    /// The input was closed before the game was over.
+    Quit,
+}
+
+/// The results of every game played in one session.
+#[derive(Debug)]
+pub struct Stats {
+    played: usize,
+    /// How many guesses each won game took.
+    wins: Vec<usize>,
+    /// How many guesses a perfect binary search needs for the range played.
+    optimal: u32,
+}
+
+impl Stats {
+    pub fn new(optimal: u32) -> Self {
+        Self {
+            played: 0,
+            wins: Vec::new(),
+            optimal,
+        }
+    }
+
+    /// Adds the result of a game. Quitting leaves the statistics as they
+    /// were.
+    pub fn record(&mut self, outcome: Outcome) {
+        match outcome {
+            Outcome::Won { guesses } => {
+                self.played += 1;
+                self.wins.push(guesses);
+            }
+            Outcome::Lost => self.played += 1,
+            Outcome::Quit => {}
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Games played: {}", self.played)?;
+
+        if self.played == 0 {
+            return Ok(());
+        }
+
+        writeln!(f, "Games won: {}", self.wins.len())?;
+
+        let Some(&best) = self.wins.iter().min() else {
+            return Ok(());
+        };
+
+        let average = self.wins.iter().sum::<usize>() as f64 / self.wins.len() as f64;
+        writeln!(
+            f,
+            "Average guesses: {average:.1} (a binary search needs at most {})",
+            self.optimal
+        )?;
+        writeln!(
+            f,
+            "Best game: {best} {}",
+            if best == 1 { "guess" } else { "guesses" }
+        )
+    }
+}
+
+/// Rates a game won in `guesses` against the `optimal` number of guesses,
+/// e.g. `Efficiency: 88% (Great)`.
+pub fn efficiency(guesses: usize, optimal: u32) -> String {
+    let optimal = optimal as usize;
+    let percent = (optimal * 100 / guesses.max(1)).min(100);
+
+    let rating = match guesses {
+        _ if guesses < optimal => "Lucky",
+        _ if guesses == optimal => "Perfect",
+        _ if guesses <= optimal + 2 => "Great",
+        _ if guesses <= optimal * 2 => "Good",
+        _ => "Keep practising",
+    };
+
+    format!("Efficiency: {percent}% ({rating})")
+}
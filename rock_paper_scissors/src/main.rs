@@ -66,7 +66,7 @@ impl App {
                 }
             };
 
-            let computer_move = match rng.gen_range(0..3) {
+            let computer_move = match rng.random_range(0..3) {
                 0 => Move::Rock,
                 1 => Move::Paper,
                 2 => Move::Scissors,
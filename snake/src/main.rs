@@ -20,6 +20,10 @@ enum Direction {
     Down,
     Left,
     Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
 }
 
 impl Direction {
@@ -30,9 +34,21 @@ impl Direction {
             Direction::Down => Direction::Up,
             Direction::Left => Direction::Right,
             Direction::Right => Direction::Left,
+            Direction::UpLeft => Direction::DownRight,
+            Direction::UpRight => Direction::DownLeft,
+            Direction::DownLeft => Direction::UpRight,
+            Direction::DownRight => Direction::UpLeft,
         }
     }
 
+    /// Checks if the direction moves along both axes at once
+    fn is_diagonal(&self) -> bool {
+        matches!(
+            self,
+            Direction::UpLeft | Direction::UpRight | Direction::DownLeft | Direction::DownRight
+        )
+    }
+
     /// Checks if `other` is the opposite duirection
     fn is_opposite(&self, other: Direction) -> bool {
         self.opposite() == other
@@ -46,6 +62,8 @@ impl From<&Direction> for char {
             Direction::Down => 'v',
             Direction::Left => '<',
             Direction::Right => '>',
+            Direction::UpLeft | Direction::DownRight => '\\',
+            Direction::UpRight | Direction::DownLeft => '/',
         }
     }
 }
@@ -57,6 +75,145 @@ impl Display for Direction {
     }
 }
 
+/// The kind of grid the snake moves on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Variant {
+    /// A square grid with the four arrow key directions.
+    #[default]
+    Classic,
+    /// A square grid that also allows moving diagonally.
+    Diagonal,
+    /// A hexagonal grid where every odd row is shifted half a tile right.
+    Hex,
+}
+
+impl Variant {
+    /// Reads the variant from the command line arguments.
+    fn from_args() -> Result<Self> {
+        let mut variant = Variant::default();
+
+        for arg in std::env::args().skip(1) {
+            variant = match arg.as_str() {
+                "--classic" => Variant::Classic,
+                "--diagonal" => Variant::Diagonal,
+                "--hex" => Variant::Hex,
+                _ => {
+                    return Err(anyhow!(
+                        "Unknown argument `{arg}`. Use --classic, --diagonal or --hex."
+                    ))
+                }
+            };
+        }
+
+        Ok(variant)
+    }
+
+    /// The directions the snake can move in on this grid.
+    fn directions(&self) -> &'static [Direction] {
+        match self {
+            Variant::Classic => &[
+                Direction::Up,
+                Direction::Down,
+                Direction::Left,
+                Direction::Right,
+            ],
+            Variant::Diagonal => &[
+                Direction::Up,
+                Direction::Down,
+                Direction::Left,
+                Direction::Right,
+                Direction::UpLeft,
+                Direction::UpRight,
+                Direction::DownLeft,
+                Direction::DownRight,
+            ],
+            Variant::Hex => &[
+                Direction::Left,
+                Direction::Right,
+                Direction::UpLeft,
+                Direction::UpRight,
+                Direction::DownLeft,
+                Direction::DownRight,
+            ],
+        }
+    }
+
+    /// Checks if `direction` is a valid move on this grid.
+    fn allows(&self, direction: Direction) -> bool {
+        self.directions().contains(&direction)
+    }
+
+    /// Maps a key press to a direction on this grid.
+    ///
+    /// The arrow keys always work, `q`/`e`/`z`/`c` and the numpad corner keys
+    /// (`Home`, `PageUp`, `End`, `PageDown`) move diagonally.
+    fn direction_for(&self, key: &Key) -> Option<Direction> {
+        let direction = match key {
+            Key::ArrowLeft | Key::Char('a') => Direction::Left,
+            Key::ArrowRight | Key::Char('d') => Direction::Right,
+            Key::ArrowUp | Key::Char('w') => Direction::Up,
+            Key::ArrowDown | Key::Char('x') => Direction::Down,
+            Key::Home | Key::Char('q') => Direction::UpLeft,
+            Key::PageUp | Key::Char('e') => Direction::UpRight,
+            Key::End | Key::Char('z') => Direction::DownLeft,
+            Key::PageDown | Key::Char('c') => Direction::DownRight,
+            _ => return None,
+        };
+
+        self.allows(direction).then_some(direction)
+    }
+
+    /// Returns the position one step from `from` in `direction`.
+    fn step(&self, from: Position, direction: Direction) -> Position {
+        let (dx, dy) = match (self, direction) {
+            (_, Direction::Up) => (0, -1),
+            (_, Direction::Down) => (0, 1),
+            (_, Direction::Left) => (-1, 0),
+            (_, Direction::Right) => (1, 0),
+            // On the hex grid the diagonal neighbours depend on whether the
+            // row is shifted or not.
+            (Variant::Hex, Direction::UpLeft) => (from.y.rem_euclid(2) - 1, -1),
+            (Variant::Hex, Direction::UpRight) => (from.y.rem_euclid(2), -1),
+            (Variant::Hex, Direction::DownLeft) => (from.y.rem_euclid(2) - 1, 1),
+            (Variant::Hex, Direction::DownRight) => (from.y.rem_euclid(2), 1),
+            (_, Direction::UpLeft) => (-1, -1),
+            (_, Direction::UpRight) => (1, -1),
+            (_, Direction::DownLeft) => (-1, 1),
+            (_, Direction::DownRight) => (1, 1),
+        };
+
+        Position {
+            x: from.x + dx,
+            y: from.y + dy,
+        }
+    }
+
+    /// The size of the grid that fits in a terminal of the given size.
+    fn grid_size(&self, width: isize, height: isize) -> (isize, isize) {
+        match self {
+            Variant::Classic | Variant::Diagonal => (width, height),
+            // Every hex tile is two columns wide and odd rows need one extra.
+            Variant::Hex => ((width - 1) / 2, height),
+        }
+    }
+
+    /// The terminal column and row that `position` is rendered at.
+    fn screen_position(&self, position: Position) -> (usize, usize) {
+        match self {
+            Variant::Classic | Variant::Diagonal => (position.x as usize, position.y as usize),
+            Variant::Hex => (
+                (position.x * 2 + position.y.rem_euclid(2)) as usize,
+                position.y as usize,
+            ),
+        }
+    }
+}
+
+/// Switches the terminal to the alternate screen buffer.
+const ENTER_ALTERNATE_SCREEN: &str = "\x1b[?1049h";
+/// Restores the terminal's main screen buffer and its scrollback.
+const LEAVE_ALTERNATE_SCREEN: &str = "\x1b[?1049l";
+
 /// Each pixel that is rendered to the console.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 #[repr(usize)]
@@ -79,6 +236,7 @@ struct Position {
 struct Game {
     random: ThreadRng,
     term: Term,
+    variant: Variant,
     sleep: Duration,
     width: isize,
     height: isize,
@@ -99,12 +257,11 @@ enum Msg {
 
 impl Game {
     /// Creates a new [`Game`] object.
-    fn new() -> Self {
+    fn new(variant: Variant) -> Self {
         let (width, height): (isize, isize);
 
         if let Some((Width(w), Height(h))) = terminal_size::terminal_size() {
-            width = w as isize;
-            height = h as isize;
+            (width, height) = variant.grid_size(w as isize, h as isize);
         } else {
             width = 0;
             height = 0;
@@ -113,6 +270,7 @@ impl Game {
         Self {
             random: ThreadRng::default(),
             term: Term::stdout(),
+            variant,
             direction: None,
             width,
             height,
@@ -129,13 +287,13 @@ impl Game {
 
     /// Initializes the [`Game`] object.
     fn init(&mut self) -> Result<()> {
+        self.term.write_str(ENTER_ALTERNATE_SCREEN)?;
         self.term.hide_cursor()?;
         self.term.clear_screen()?;
         self.snake.push_back(self.position);
         self.map[self.position.x as usize][self.position.y as usize] = Some(Tile::Snake);
         self.position_food()?;
-        self.term
-            .move_cursor_to(self.position.x as usize, self.position.y as usize)?;
+        self.move_cursor_to(self.position)?;
         self.term.write_all(b"@")?;
 
         while self.direction.is_none() && !self.close_requested {
@@ -146,35 +304,40 @@ impl Game {
         Ok(())
     }
 
+    /// Never leaves the user stuck on the alternate screen if the game
+    /// failed before it could close normally.
+    fn restore_after_error(&self) -> Result<()> {
+        if !self.close_requested {
+            self.term.show_cursor()?;
+            self.term.write_str(LEAVE_ALTERNATE_SCREEN)?;
+        }
+
+        Ok(())
+    }
+
     /// Renders the [`Game`] object to the console.
     fn render(&mut self) -> Result<()> {
         if let Some((Width(w), Height(h))) = terminal_size::terminal_size() {
-            if self.width != w as isize && self.height != h as isize {
+            let (width, height) = self.variant.grid_size(w as isize, h as isize);
+
+            if self.width != width && self.height != height {
                 self.update(Msg::Close {
-                    message: "Console was resized. Snake game has ended.".to_string(),
+                    message: format!(
+                        "Console was resized. Snake game has ended. Score: {}.\n",
+                        self.snake.len() - 1
+                    ),
                 })?;
 
                 return Ok(());
             }
 
+            let previous = self.position;
+
             if let Some(direction) = self.direction {
-                match direction {
-                    Direction::Up => self.position.y -= 1,
-                    Direction::Down => self.position.y += 1,
-                    Direction::Left => self.position.x -= 1,
-                    Direction::Right => self.position.x += 1,
-                }
+                self.position = self.variant.step(self.position, direction);
             }
 
-            if self.position.x < 0
-                || self.position.y < 0
-                || self.position.x >= self.width
-                || self.position.y >= self.height
-                || matches!(
-                    self.map[self.position.x as usize][self.position.y as usize],
-                    Some(Tile::Snake)
-                )
-            {
+            if self.collides(previous, self.position) {
                 self.update(Msg::Close {
                     message: format!("Game Over. Score: {}.\n", self.snake.len() - 1),
                 })?;
@@ -182,8 +345,7 @@ impl Game {
                 return Ok(());
             }
 
-            self.term
-                .move_cursor_to(self.position.x as usize, self.position.y as usize)?;
+            self.move_cursor_to(self.position)?;
 
             if let Some(direction) = self.direction {
                 self.term.write_all(direction.to_string().as_bytes())?
@@ -197,9 +359,9 @@ impl Game {
             if self.map[self.position.x as usize][self.position.y as usize] == Some(Tile::Food) {
                 self.position_food()?
             } else {
-                let Position { x, y } = self.snake.pop_front().ok_or(anyhow!("Cannot dequeue"))?;
-                self.map[x as usize][y as usize] = Some(Tile::Open);
-                self.term.move_cursor_to(x as usize, y as usize)?;
+                let tail = self.snake.pop_front().ok_or(anyhow!("Cannot dequeue"))?;
+                self.map[tail.x as usize][tail.y as usize] = Some(Tile::Open);
+                self.move_cursor_to(tail)?;
                 self.term.write_all(b" ")?;
             }
 
@@ -218,6 +380,10 @@ impl Game {
     fn update(&mut self, msg: Msg) -> Result<()> {
         match msg {
             Msg::MoveDirection(direction) => {
+                if !self.variant.allows(direction) {
+                    return Ok(());
+                }
+
                 if let Some(current_direction) = self.direction {
                     if direction.is_opposite(current_direction) {
                         return Ok(());
@@ -227,19 +393,25 @@ impl Game {
                 self.direction = Some(direction)
             }
             Msg::OnKeyPress(key) => match key {
-                console::Key::ArrowLeft => self.update(Msg::MoveDirection(Direction::Left))?,
-                console::Key::ArrowRight => self.update(Msg::MoveDirection(Direction::Right))?,
-                console::Key::ArrowUp => self.update(Msg::MoveDirection(Direction::Up))?,
-                console::Key::ArrowDown => self.update(Msg::MoveDirection(Direction::Down))?,
                 console::Key::Escape => self.update(Msg::Close {
-                    message: "Snake game closed".to_string(),
+                    message: format!("Snake game closed. Score: {}.\n", self.snake.len() - 1),
                 })?,
-                _ => return Ok(()),
+                _ => {
+                    if let Some(direction) = self.variant.direction_for(&key) {
+                        self.update(Msg::MoveDirection(direction))?
+                    }
+                }
             },
             Msg::Close { message } => {
+                if self.close_requested {
+                    return Ok(());
+                }
+
+                // Leave the alternate screen before printing so the message
+                // ends up below the user's untouched scrollback.
                 self.close_requested = true;
                 self.term.show_cursor()?;
-                self.term.clear_screen()?;
+                self.term.write_str(LEAVE_ALTERNATE_SCREEN)?;
                 self.term.write_all(message.as_bytes())?;
             }
         };
@@ -256,21 +428,57 @@ impl Game {
             }
         }
 
-        let index = self.random.gen_range(0..posible_coords.len());
+        let index = self.random.random_range(0..posible_coords.len());
 
-        let Position { x, y } = posible_coords[index];
-        self.map[x as usize][y as usize] = Some(Tile::Food);
-        self.term.move_cursor_to(x as usize, y as usize)?;
+        let food = posible_coords[index];
+        self.map[food.x as usize][food.y as usize] = Some(Tile::Food);
+        self.move_cursor_to(food)?;
         self.term.write_all(b"+")?;
 
         Ok(())
     }
+
+    /// Checks if moving from `from` to `to` ends the game.
+    fn collides(&self, from: Position, to: Position) -> bool {
+        if to.x < 0 || to.y < 0 || to.x >= self.width || to.y >= self.height {
+            return true;
+        }
+
+        if self.tile(to) == Some(Tile::Snake) {
+            return true;
+        }
+
+        // On the diagonal grid the snake cannot slip between two of its own
+        // segments that touch at the corners.
+        self.variant == Variant::Diagonal
+            && self
+                .direction
+                .is_some_and(|direction| direction.is_diagonal())
+            && self.tile(Position { x: to.x, y: from.y }) == Some(Tile::Snake)
+            && self.tile(Position { x: from.x, y: to.y }) == Some(Tile::Snake)
+    }
+
+    /// The tile at `position`, which must be inside the grid.
+    fn tile(&self, position: Position) -> Option<Tile> {
+        self.map[position.x as usize][position.y as usize]
+    }
+
+    /// Moves the cursor to where `position` is drawn for the current variant.
+    fn move_cursor_to(&self, position: Position) -> Result<()> {
+        let (x, y) = self.variant.screen_position(position);
+        self.term.move_cursor_to(x, y)?;
+
+        Ok(())
+    }
 }
 
 fn main() -> Result<()> {
     let (tx, rx) = mpsc::channel();
-    let mut game = Game::new();
-    game.init()?;
+    let mut game = Game::new(Variant::from_args()?);
+    if let Err(error) = game.init() {
+        game.restore_after_error()?;
+        return Err(error);
+    }
 
     let captured_term = game.term.clone();
 
@@ -297,16 +505,22 @@ fn main() -> Result<()> {
     });
 
     // This is the main game loop that will be rendering the game
-    loop {
-        game.render()?;
-        if let std::result::Result::Ok(key) = rx.try_recv() {
-            game.update(Msg::OnKeyPress(key))?;
-        }
+    let outcome = (|| -> Result<()> {
+        loop {
+            game.render()?;
+            if let std::result::Result::Ok(key) = rx.try_recv() {
+                game.update(Msg::OnKeyPress(key))?;
+            }
 
-        if game.close_requested {
-            break;
+            if game.close_requested {
+                break Ok(());
+            }
         }
+    })();
+
+    if outcome.is_err() {
+        game.restore_after_error()?;
     }
 
-    Ok(())
+    outcome
 }